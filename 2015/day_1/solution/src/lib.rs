@@ -0,0 +1,47 @@
+use aoc_runner::parse::ParseError;
+use aoc_runner::{Params, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 1;
+
+    type Input = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+        input
+            .char_indices()
+            .filter(|(_, c)| !c.is_whitespace())
+            .map(|(i, c)| match c {
+                '(' => Ok(1),
+                ')' => Ok(-1),
+                _ => Err(ParseError::at(input, &input[i..i + c.len_utf8()], "( or )")),
+            })
+            .collect()
+    }
+
+    fn part1(moves: &Vec<i32>, _params: &Params) -> i32 {
+        moves.iter().sum()
+    }
+
+    fn part2(moves: &Vec<i32>, _params: &Params) -> i32 {
+        let mut floor = 0;
+        for (i, x) in moves.iter().enumerate() {
+            floor += x;
+            if floor == -1 {
+                return (i + 1) as i32;
+            }
+        }
+        panic!("Couldn't find answer")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_runner::example_tests!(Day1);
+}
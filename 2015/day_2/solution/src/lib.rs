@@ -0,0 +1,64 @@
+use aoc_runner::parse::{self, ParseError};
+use aoc_runner::{Params, Solution};
+use std::cmp::min;
+
+#[derive(Debug)]
+pub struct Present {
+    l: u32,
+    w: u32,
+    h: u32,
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 2;
+
+    type Input = Vec<Present>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Present>, ParseError> {
+        parse::lines(input, |line| {
+            let (l, rest) = parse::split_once(line, line, "x")?;
+            let (w, h) = parse::split_once(line, rest, "x")?;
+            Ok(Present {
+                l: parse::number(line, l)?,
+                w: parse::number(line, w)?,
+                h: parse::number(line, h)?,
+            })
+        })
+    }
+
+    fn part1(presents: &Vec<Present>, _params: &Params) -> u32 {
+        presents
+            .iter()
+            .map(|Present { l, w, h }| {
+                let a = l * w;
+                let b = l * h;
+                let c = w * h;
+                2 * a + 2 * b + 2 * c + min(min(a, b), c)
+            })
+            .sum()
+    }
+
+    fn part2(presents: &Vec<Present>, _params: &Params) -> u32 {
+        presents
+            .iter()
+            .map(|Present { l, w, h }| {
+                let a = 2 * (l + w);
+                let b = 2 * (l + h);
+                let c = 2 * (w + h);
+                min(min(a, b), c) + l * w * h
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_runner::example_tests!(Day2);
+}
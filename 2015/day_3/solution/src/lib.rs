@@ -0,0 +1,75 @@
+use aoc_runner::parse::ParseError;
+use aoc_runner::{Params, Solution};
+use std::collections::HashSet;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 3;
+
+    type Input = Vec<(i32, i32)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<(i32, i32)>, ParseError> {
+        input
+            .char_indices()
+            .filter(|(_, direction)| !direction.is_whitespace())
+            .map(|(i, direction)| match direction {
+                '^' => Ok((0, 1)),
+                'v' => Ok((0, -1)),
+                '>' => Ok((1, 0)),
+                '<' => Ok((-1, 0)),
+                _ => Err(ParseError::at(
+                    input,
+                    &input[i..i + direction.len_utf8()],
+                    "one of ^, v, >, <",
+                )),
+            })
+            .collect()
+    }
+
+    fn part1(moves: &Vec<(i32, i32)>, _params: &Params) -> usize {
+        let mut houses = HashSet::new();
+
+        let mut x = 0;
+        let mut y = 0;
+        for (dx, dy) in moves {
+            houses.insert((x, y));
+            x += dx;
+            y += dy;
+        }
+
+        houses.len()
+    }
+
+    fn part2(moves: &Vec<(i32, i32)>, _params: &Params) -> usize {
+        let mut houses = HashSet::new();
+
+        let mut x_a = 0;
+        let mut y_a = 0;
+        let mut x_b = 0;
+        let mut y_b = 0;
+        for (i, (dx, dy)) in moves.iter().enumerate() {
+            if i % 2 == 0 {
+                houses.insert((x_a, y_a));
+                x_a += dx;
+                y_a += dy;
+            } else {
+                houses.insert((x_b, y_b));
+                x_b += dx;
+                y_b += dy;
+            }
+        }
+
+        houses.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_runner::example_tests!(Day3);
+}
@@ -0,0 +1,48 @@
+use aoc_runner::parse::ParseError;
+use aoc_runner::{Params, Solution};
+
+/// Returns the lowest number that, appended to the prefix, gives an MD5 hash starting with the
+/// given number of zeroes.
+fn mine(prefix: &str, zeroes: usize) -> u64 {
+    let target = "0".repeat(zeroes);
+
+    let mut i = 0;
+    loop {
+        let hash = md5::compute(format!("{}{:05}", prefix, i));
+        let hash = format!("{:x}", hash);
+        if hash.starts_with(&target) {
+            return i;
+        }
+        i += 1;
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 4;
+
+    type Input = String;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(prefix: &String, _params: &Params) -> u64 {
+        mine(prefix, 5)
+    }
+
+    fn part2(prefix: &String, _params: &Params) -> u64 {
+        mine(prefix, 6)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_runner::example_tests!(Day4);
+}
@@ -0,0 +1,109 @@
+use aoc_runner::parse::ParseError;
+use aoc_runner::{Params, Solution};
+use std::collections::HashSet;
+
+fn string_is_nice(string: &str) -> bool {
+    let mut vowel_count = 0;
+    let mut contains_double = false;
+    let mut last_char: Option<char> = None;
+
+    for c in string.chars() {
+        if "aeiou".contains(c) {
+            vowel_count += 1;
+        }
+        if let Some(last_char) = last_char {
+            if last_char == c {
+                contains_double = true;
+            }
+            match format!("{}{}", last_char, c).as_str() {
+                "ab" | "cd" | "pq" | "xy" => return false,
+                _ => (),
+            }
+        }
+        last_char = Some(c);
+    }
+    vowel_count >= 3 && contains_double
+}
+
+fn string_is_really_nice(string: &str) -> bool {
+    let mut contains_pair = false;
+    let mut contains_double = false;
+
+    let mut pairs = HashSet::new();
+
+    let mut last_char: Option<char> = None;
+    let mut last_last_char: Option<char> = None;
+    let mut last_pair: Option<String> = None;
+
+    for c in string.chars() {
+        if let Some(last_last_char) = last_last_char {
+            if last_last_char == c {
+                contains_double = true;
+            }
+        }
+        if let Some(last_char) = last_char {
+            let pair = format!("{}{}", last_char, c);
+            let mut overlap = false;
+            if let Some(ref last_pair) = last_pair {
+                if last_pair.clone() == pair {
+                    overlap = true;
+                }
+            }
+            if pairs.contains(&pair) && !overlap {
+                contains_pair = true;
+            } else {
+                pairs.insert(pair.clone());
+            }
+            if overlap {
+                last_pair = None;
+            } else {
+                last_pair = Some(pair);
+            }
+        }
+        last_last_char = last_char;
+        last_char = Some(c);
+    }
+    contains_double && contains_pair
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 5;
+
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        Ok(input.lines().map(|s| s.to_string()).collect())
+    }
+
+    fn part1(strings: &Vec<String>, _params: &Params) -> usize {
+        strings.iter().filter(|s| string_is_nice(s)).count()
+    }
+
+    fn part2(strings: &Vec<String>, _params: &Params) -> usize {
+        strings.iter().filter(|s| string_is_really_nice(s)).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_runner::example_tests!(Day5);
+
+    #[test]
+    fn solves_aaa() {
+        let content = "aaa".to_string();
+        assert_eq!(Day5::solve_part2(&content), 0);
+    }
+
+    #[test]
+    fn solves_aaaa() {
+        let content = "aaaa".to_string();
+        assert_eq!(Day5::solve_part2(&content), 1);
+    }
+}
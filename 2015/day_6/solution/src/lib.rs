@@ -0,0 +1,121 @@
+use aoc_grid::Grid;
+use aoc_runner::parse::{self, ParseError};
+use aoc_runner::{Params, Solution};
+
+const SIZE: usize = 1000;
+
+/// Changes the brightness of the lights in the rectangle between two opposite corners.
+fn change(
+    lights: &mut Grid<u64>,
+    from: (usize, usize),
+    to: (usize, usize),
+    f: impl Fn(u64) -> u64,
+) {
+    for x in from.0..=to.0 {
+        for y in from.1..=to.1 {
+            lights[(x, y)] = f(lights[(x, y)]);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Action {
+    TurnOn,
+    TurnOff,
+    Toggle,
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    action: Action,
+    from: (usize, usize),
+    to: (usize, usize),
+}
+
+/// Parses a corner such as `0,999`.
+fn parse_corner(line: &str, corner: &str) -> Result<(usize, usize), ParseError> {
+    let (x, y) = parse::split_once(line, corner, ",")?;
+    let coordinate = |text| match parse::number(line, text)? {
+        n if n < SIZE => Ok(n),
+        _ => Err(ParseError::at(line, text, "a coordinate below 1000")),
+    };
+    Ok((coordinate(x)?, coordinate(y)?))
+}
+
+impl Instruction {
+    fn from_line(line: &str) -> Result<Self, ParseError> {
+        let actions = [
+            ("turn on ", Action::TurnOn),
+            ("turn off ", Action::TurnOff),
+            ("toggle ", Action::Toggle),
+        ];
+        let (action, corners) = actions
+            .iter()
+            .find_map(|(prefix, action)| Some((*action, line.strip_prefix(prefix)?)))
+            .ok_or_else(|| {
+                let word = line.split(' ').next().unwrap_or_default();
+                ParseError::at(line, word, "turn on, turn off or toggle")
+            })?;
+        let (from, to) = parse::split_once(line, corners, " through ")?;
+        Ok(Self {
+            action,
+            from: parse_corner(line, from)?,
+            to: parse_corner(line, to)?,
+        })
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 6;
+
+    type Input = Vec<Instruction>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        parse::lines(input, Instruction::from_line)
+    }
+
+    fn part1(instructions: &Vec<Instruction>, _params: &Params) -> u64 {
+        let mut lights = Grid::filled(SIZE, SIZE, 0);
+        for Instruction { action, from, to } in instructions {
+            match action {
+                Action::TurnOn => change(&mut lights, *from, *to, |_| 1),
+                Action::TurnOff => change(&mut lights, *from, *to, |_| 0),
+                Action::Toggle => change(&mut lights, *from, *to, |light| 1 - light),
+            }
+        }
+        lights.values().sum()
+    }
+
+    fn part2(instructions: &Vec<Instruction>, _params: &Params) -> u64 {
+        let mut lights = Grid::filled(SIZE, SIZE, 0);
+        for Instruction { action, from, to } in instructions {
+            match action {
+                Action::TurnOn => change(&mut lights, *from, *to, |light| light + 1),
+                Action::TurnOff => change(&mut lights, *from, *to, |light| light.saturating_sub(1)),
+                Action::Toggle => change(&mut lights, *from, *to, |light| light + 2),
+            }
+        }
+        lights.values().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_runner::example_tests!(Day6);
+
+    #[test]
+    fn rejects_corners_off_the_grid() {
+        let error = Day6::parse("toggle 0,0 through 999,1000\n").err().unwrap();
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (1, 24, "1000")
+        );
+    }
+}
@@ -0,0 +1,221 @@
+use aoc_runner::parse::{self, ParseError};
+use aoc_runner::{Params, Solution};
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Signal {
+    name: String,
+}
+
+impl Signal {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Value {
+    Constant(u16),
+    Variable(Signal),
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Value(Value),
+    And,
+    Or,
+    Not,
+    LShift,
+    RShift,
+}
+
+#[derive(Debug)]
+pub enum Instruction {
+    Assign(Value),
+    And(Value, Value),
+    Or(Value, Value),
+    Not(Value),
+    LShift(Value, Value),
+    RShift(Value, Value),
+}
+
+fn execute(
+    instructions: &HashMap<String, Instruction>,
+    signal: &str,
+    cache: &mut HashMap<String, u16>,
+) -> u16 {
+    if let Some(x) = cache.get(signal) {
+        return *x;
+    }
+    let value = match instructions.get(signal).unwrap() {
+        Instruction::Assign(value) => match value {
+            Value::Constant(x) => *x,
+            Value::Variable(signal) => execute(instructions, &signal.name, cache),
+        },
+        Instruction::And(left, right) => {
+            let left = match left {
+                Value::Constant(x) => *x,
+                Value::Variable(signal) => execute(instructions, &signal.name, cache),
+            };
+            let right = match right {
+                Value::Constant(x) => *x,
+                Value::Variable(signal) => execute(instructions, &signal.name, cache),
+            };
+            left & right
+        }
+        Instruction::Or(left, right) => {
+            let left = match left {
+                Value::Constant(x) => *x,
+                Value::Variable(signal) => execute(instructions, &signal.name, cache),
+            };
+            let right = match right {
+                Value::Constant(x) => *x,
+                Value::Variable(signal) => execute(instructions, &signal.name, cache),
+            };
+            left | right
+        }
+        Instruction::LShift(left, right) => {
+            let left = match left {
+                Value::Constant(x) => *x,
+                Value::Variable(signal) => execute(instructions, &signal.name, cache),
+            };
+            let right = match right {
+                Value::Constant(x) => *x,
+                Value::Variable(signal) => execute(instructions, &signal.name, cache),
+            };
+            left << right
+        }
+        Instruction::RShift(left, right) => {
+            let left = match left {
+                Value::Constant(x) => *x,
+                Value::Variable(signal) => execute(instructions, &signal.name, cache),
+            };
+            let right = match right {
+                Value::Constant(x) => *x,
+                Value::Variable(signal) => execute(instructions, &signal.name, cache),
+            };
+            left >> right
+        }
+        Instruction::Not(value) => {
+            let value = match value {
+                Value::Constant(x) => *x,
+                Value::Variable(signal) => execute(instructions, &signal.name, cache),
+            };
+            !value
+        }
+    };
+    cache.insert(signal.to_string(), value);
+    value
+}
+
+impl Token {
+    fn from_word(word: &str) -> Self {
+        match word {
+            "AND" => Token::And,
+            "OR" => Token::Or,
+            "NOT" => Token::Not,
+            "LSHIFT" => Token::LShift,
+            "RSHIFT" => Token::RShift,
+            value => match value.parse() {
+                Ok(x) => Token::Value(Value::Constant(x)),
+                Err(_) => Token::Value(Value::Variable(Signal::new(value))),
+            },
+        }
+    }
+}
+
+/// Parses a line such as `x AND y -> z` into the signal it sets and how.
+fn parse_instruction(line: &str) -> Result<(String, Instruction), ParseError> {
+    let (expression, signal) = parse::split_once(line, line, " -> ")?;
+    let signal = match Token::from_word(signal) {
+        Token::Value(Value::Variable(signal)) => signal.name,
+        _ => return Err(ParseError::at(line, signal, "a wire after ->")),
+    };
+    let tokens: Vec<(&str, Token)> = expression
+        .split_whitespace()
+        .map(|word| (word, Token::from_word(word)))
+        .collect();
+    let value = |(word, token): &(&str, Token), expected: &str| match token {
+        Token::Value(x) => Ok(x.clone()),
+        _ => Err(ParseError::at(line, word, expected)),
+    };
+    let instruction = match tokens.as_slice() {
+        [(_, Token::Not), operand] => Instruction::Not(value(operand, "a value after NOT")?),
+        [operand] => Instruction::Assign(value(operand, "a value before ->")?),
+        [left, (word, operator), right] => {
+            let left = value(left, &format!("a value before {}", word))?;
+            let right = value(right, &format!("a value after {}", word))?;
+            match operator {
+                Token::And => Instruction::And(left, right),
+                Token::Or => Instruction::Or(left, right),
+                Token::LShift => Instruction::LShift(left, right),
+                Token::RShift => Instruction::RShift(left, right),
+                _ => return Err(ParseError::at(line, word, "AND, OR, LSHIFT or RSHIFT")),
+            }
+        }
+        _ => {
+            return Err(ParseError::at(
+                line,
+                expression,
+                "a value, NOT or an operation",
+            ))
+        }
+    };
+    Ok((signal, instruction))
+}
+
+fn parse_instructions(input: &str) -> Result<HashMap<String, Instruction>, ParseError> {
+    Ok(parse::lines(input, parse_instruction)?
+        .into_iter()
+        .collect())
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 7;
+    const PART1_PARAMS: &'static [(&'static str, &'static str)] = &[("wire", "a")];
+
+    type Input = HashMap<String, Instruction>;
+    type Answer1 = u16;
+    type Answer2 = u16;
+
+    fn parse(input: &str) -> Result<HashMap<String, Instruction>, ParseError> {
+        parse_instructions(input)
+    }
+
+    fn part1(instructions: &HashMap<String, Instruction>, params: &Params) -> u16 {
+        let mut cache = HashMap::new();
+        let wire: String = params.get("wire");
+        execute(instructions, &wire, &mut cache)
+    }
+
+    fn part2(instructions: &HashMap<String, Instruction>, _params: &Params) -> u16 {
+        let mut cache = HashMap::new();
+        let value = execute(instructions, "a", &mut cache);
+        let mut cache = HashMap::new();
+        cache.insert("b".to_string(), value);
+        execute(instructions, "a", &mut cache)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_runner::example_tests!(Day7);
+
+    #[test]
+    fn reports_syntax_errors() {
+        let error = Day7::parse("123 -> x\nx AND NOT -> y\n").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 7: expected a value after AND, found \"NOT\""
+        );
+        let error = Day7::parse("x OR y -> 5\n").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 11));
+    }
+}
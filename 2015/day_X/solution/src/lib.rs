@@ -0,0 +1,32 @@
+use aoc_runner::parse::ParseError;
+use aoc_runner::{Params, Solution};
+
+pub struct DayX;
+
+impl Solution for DayX {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 0;
+
+    type Input = String;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(_input: &String, _params: &Params) -> i64 {
+        0
+    }
+
+    fn part2(_input: &String, _params: &Params) -> i64 {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_runner::example_tests!(DayX);
+}
@@ -0,0 +1,42 @@
+use aoc_runner::parse::{self, ParseError};
+use aoc_runner::{Params, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+
+    type Input = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    /// Returns the total calories carried by each elf.
+    fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+        input
+            .split("\n\n")
+            .map(|group| {
+                let calories = parse::lines(group, |line| parse::number::<u64>(line, line))
+                    .map_err(|e| e.within(input, group))?;
+                Ok(calories.into_iter().sum())
+            })
+            .collect()
+    }
+
+    fn part1(sums: &Vec<u64>, _params: &Params) -> u64 {
+        *sums.iter().max().unwrap()
+    }
+
+    fn part2(sums: &Vec<u64>, _params: &Params) -> u64 {
+        let mut sums = sums.clone();
+        sums.sort();
+        sums[sums.len() - 3..].iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_runner::example_tests!(Day1);
+}
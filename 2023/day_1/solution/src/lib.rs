@@ -0,0 +1,81 @@
+use aoc_runner::parse::ParseError;
+use aoc_runner::{Params, Solution};
+
+const SPELLED_DIGITS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// A digit in a line of the calibration document, either written as a digit or spelled out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Digit {
+    value: i64,
+    spelled: bool,
+}
+
+/// Returns the digits that start at each position of a line, in order. Spelled out digits may
+/// overlap, as in `oneight`.
+fn digits(line: &str) -> Vec<Digit> {
+    line.char_indices()
+        .filter_map(|(i, c)| {
+            if let Some(value) = c.to_digit(10) {
+                return Some(Digit {
+                    value: value as i64,
+                    spelled: false,
+                });
+            }
+            let value = SPELLED_DIGITS
+                .iter()
+                .position(|digit| line[i..].starts_with(digit))?;
+            Some(Digit {
+                value: value as i64 + 1,
+                spelled: true,
+            })
+        })
+        .collect()
+}
+
+/// Sums the numbers formed by the first and last of the given digits of each line, where lines
+/// without digits count as 0.
+fn calibration_sum(lines: &[Vec<Digit>], counts: impl Fn(&Digit) -> bool) -> i64 {
+    lines
+        .iter()
+        .map(|digits| {
+            let mut digits = digits.iter().filter(|digit| counts(digit));
+            match (digits.next(), digits.next_back()) {
+                (Some(first), Some(last)) => first.value * 10 + last.value,
+                (Some(only), None) => only.value * 11,
+                _ => 0,
+            }
+        })
+        .sum()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+
+    type Input = Vec<Vec<Digit>>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Vec<Digit>>, ParseError> {
+        Ok(input.split('\n').map(digits).collect())
+    }
+
+    fn part1(lines: &Vec<Vec<Digit>>, _params: &Params) -> i64 {
+        calibration_sum(lines, |digit| !digit.spelled)
+    }
+
+    fn part2(lines: &Vec<Vec<Digit>>, _params: &Params) -> i64 {
+        calibration_sum(lines, |_| true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_runner::example_tests!(Day1);
+}
@@ -0,0 +1,221 @@
+use aoc_grid::geometry::{Direction, Point};
+use aoc_grid::Grid;
+use aoc_runner::parse::ParseError;
+use aoc_runner::{Params, Solution};
+use std::collections::HashSet;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum NodeShape {
+    NS,
+    EW,
+    NW,
+    SW,
+    NE,
+    SE,
+}
+
+impl NodeShape {
+    /// Returns the directions in which the pipe connects.
+    fn directions(self) -> [Direction; 2] {
+        match self {
+            NodeShape::NS => [Direction::North, Direction::South],
+            NodeShape::EW => [Direction::West, Direction::East],
+            NodeShape::NE => [Direction::North, Direction::East],
+            NodeShape::NW => [Direction::West, Direction::North],
+            NodeShape::SW => [Direction::West, Direction::South],
+            NodeShape::SE => [Direction::East, Direction::South],
+        }
+    }
+}
+
+impl From<(Direction, Direction)> for NodeShape {
+    fn from(directions: (Direction, Direction)) -> Self {
+        [
+            NodeShape::NS,
+            NodeShape::EW,
+            NodeShape::NW,
+            NodeShape::SW,
+            NodeShape::NE,
+            NodeShape::SE,
+        ]
+        .into_iter()
+        .find(|shape| {
+            let [a, b] = shape.directions();
+            directions == (a, b) || directions == (b, a)
+        })
+        .unwrap()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Node {
+    neighbours: [Point; 2],
+    shape: NodeShape,
+}
+
+#[derive(Debug)]
+pub struct Pipes {
+    nodes: Grid<Option<Node>>,
+    start: Point,
+}
+
+/// Returns the pipe of a character at a position.
+fn node(c: char, position: Point) -> Option<Node> {
+    let shape = match c {
+        '|' => NodeShape::NS,
+        '-' => NodeShape::EW,
+        'L' => NodeShape::NE,
+        'J' => NodeShape::NW,
+        '7' => NodeShape::SW,
+        'F' => NodeShape::SE,
+        _ => return None,
+    };
+    Some(Node {
+        neighbours: shape.directions().map(|direction| position.step(direction)),
+        shape,
+    })
+}
+
+impl Pipes {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let tiles = Grid::parse(
+            input,
+            |c| "|-LJ7FS.".contains(c).then_some(c),
+            "a pipe, . or S",
+        )?;
+        let mut nodes = Grid::from_fn(tiles.width(), tiles.height(), |position| {
+            node(tiles[position], position.into())
+        });
+
+        // Set starting point
+        let Some((x, y)) = tiles.position(|c| *c == 'S') else {
+            return Err(ParseError::at(
+                input,
+                &input[input.len()..],
+                "a starting point S",
+            ));
+        };
+        let start = Point::from((x, y));
+        let mut starting_node_neighbours = vec![];
+        let mut directions = vec![];
+        for direction in Direction::ALL {
+            let Some(neighbour) = nodes.step((x, y), direction) else {
+                continue;
+            };
+            if nodes[neighbour].is_some_and(|node| node.neighbours.contains(&start)) {
+                starting_node_neighbours.push(neighbour.into());
+                directions.push(direction);
+            }
+        }
+        if starting_node_neighbours.len() != 2 {
+            let line = input.lines().nth(y).unwrap();
+            return Err(ParseError::at(
+                input,
+                &line[x..=x],
+                "a starting point connected to two pipes",
+            ));
+        }
+        nodes[(x, y)] = Some(Node {
+            neighbours: [starting_node_neighbours[0], starting_node_neighbours[1]],
+            shape: (directions[0], directions[1]).into(),
+        });
+
+        Ok(Self { nodes, start })
+    }
+
+    fn node(&self, pos: Point) -> Option<Node> {
+        self.nodes[pos.position().unwrap()]
+    }
+
+    fn main_loop(&self) -> HashSet<Point> {
+        let mut last = self.start;
+        let mut current = self.node(self.start).unwrap().neighbours[0];
+        let mut main_loop = HashSet::new();
+
+        main_loop.insert(current);
+        loop {
+            let next = *self
+                .node(current)
+                .unwrap()
+                .neighbours
+                .iter()
+                .find(|pos| **pos != last)
+                .unwrap();
+            last = current;
+            current = next;
+            main_loop.insert(current);
+
+            if current == self.start {
+                break;
+            }
+        }
+
+        main_loop
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 10;
+
+    type Input = Pipes;
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Pipes, ParseError> {
+        Pipes::new(input)
+    }
+
+    fn part1(pipes: &Pipes, _params: &Params) -> usize {
+        pipes.main_loop().len() / 2
+    }
+
+    fn part2(pipes: &Pipes, _params: &Params) -> u64 {
+        let main_loop = pipes.main_loop();
+
+        let mut dots_inside = 0;
+        for y in 0..pipes.nodes.height() as i64 {
+            let mut is_inside = false;
+            let mut loop_came_from = None;
+            for x in 0..pipes.nodes.width() as i64 {
+                if main_loop.contains(&Point::new(x, y)) {
+                    let node = pipes.node(Point::new(x, y)).unwrap();
+                    match node.shape {
+                        NodeShape::NS => {
+                            is_inside = !is_inside;
+                        }
+                        NodeShape::EW => {}
+                        NodeShape::NW => {
+                            is_inside ^= loop_came_from.expect("Main loop should be complete")
+                                == Direction::South;
+                            loop_came_from = None;
+                        }
+                        NodeShape::SW => {
+                            is_inside ^= loop_came_from.expect("Main loop should be complete")
+                                == Direction::North;
+                            loop_came_from = None;
+                        }
+                        NodeShape::NE => {
+                            loop_came_from = Some(Direction::North);
+                        }
+                        NodeShape::SE => {
+                            loop_came_from = Some(Direction::South);
+                        }
+                    }
+                } else if is_inside {
+                    dots_inside += 1;
+                }
+            }
+        }
+        dots_inside
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_runner::example_tests!(Day10);
+}
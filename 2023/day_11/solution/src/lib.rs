@@ -0,0 +1,170 @@
+use aoc_runner::parse::ParseError;
+use aoc_runner::{Params, Solution};
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy)]
+struct Galaxy {
+    location: (usize, usize),
+}
+
+/// The galaxies as seen in the image, before the universe expands.
+#[derive(Debug)]
+pub struct Image {
+    galaxies: Vec<Galaxy>,
+    width: usize,
+    height: usize,
+}
+
+impl Image {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let mut galaxies = vec![];
+        for (y, line) in input.lines().enumerate() {
+            for (x, (i, g)) in line.char_indices().enumerate() {
+                match g {
+                    '.' => {}
+                    '#' => galaxies.push(Galaxy { location: (x, y) }),
+                    _ => return Err(ParseError::at(input, &line[i..i + g.len_utf8()], ". or #")),
+                }
+            }
+        }
+
+        Ok(Self {
+            galaxies,
+            width: input.lines().next().map_or(0, |line| line.chars().count()),
+            height: input.lines().count(),
+        })
+    }
+}
+
+#[derive(Debug, Default)]
+struct Universe {
+    galaxies: Vec<Galaxy>,
+    location_map: Vec<Vec<(usize, usize)>>,
+}
+
+impl Universe {
+    fn new(image: &Image, expansion: u64) -> Self {
+        let location_map =
+            Self::calculate_location_map(&image.galaxies, image.width, image.height, expansion);
+
+        Self {
+            galaxies: image.galaxies.clone(),
+            location_map,
+        }
+    }
+
+    fn calculate_location_map(
+        galaxies: &[Galaxy],
+        width: usize,
+        height: usize,
+        expansion: u64,
+    ) -> Vec<Vec<(usize, usize)>> {
+        // Calculate location map to account for expanding of the universe
+        let mut location_map = vec![];
+        let mut non_empty_rows: HashSet<usize> = HashSet::new();
+        let mut non_empty_cols: HashSet<usize> = HashSet::new();
+
+        for galaxy in galaxies.iter() {
+            non_empty_rows.insert(galaxy.location.0);
+            non_empty_cols.insert(galaxy.location.1);
+        }
+
+        let mut dx = 0;
+        for x in 0..width {
+            let mut row = vec![];
+            if !non_empty_rows.contains(&x) {
+                dx += expansion as usize;
+            } else {
+                let mut dy = 0;
+                for y in 0..height {
+                    if !non_empty_cols.contains(&y) {
+                        dy += expansion as usize;
+                    }
+                    row.push(((x + dx), (y + dy)));
+                }
+            }
+            location_map.push(row);
+        }
+        location_map
+    }
+
+    fn galaxy_pairs(&self) -> Vec<(&Galaxy, &Galaxy)> {
+        self.galaxies
+            .iter()
+            .enumerate()
+            .flat_map(|(i, a)| self.galaxies[i + 1..].iter().map(move |b| (a, b)))
+            .collect()
+    }
+
+    fn get_expanded_location(&self, pos: (usize, usize)) -> (usize, usize) {
+        self.location_map[pos.0][pos.1]
+    }
+
+    fn distance(&self, a: &Galaxy, b: &Galaxy) -> u64 {
+        let position_a = self.get_expanded_location(a.location);
+        let position_b = self.get_expanded_location(b.location);
+        ((position_a.0 as i64 - position_b.0 as i64).abs()
+            + (position_a.1 as i64 - position_b.1 as i64).abs()) as u64
+    }
+}
+
+fn total_distance(image: &Image, expansion: u64) -> u64 {
+    let universe = Universe::new(image, expansion);
+    universe
+        .galaxy_pairs()
+        .iter()
+        .map(|(a, b)| universe.distance(a, b))
+        .sum()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 11;
+    /// The number of rows or columns added for every empty one.
+    const PART1_PARAMS: &'static [(&'static str, &'static str)] = &[("expansion", "1")];
+    const PART2_PARAMS: &'static [(&'static str, &'static str)] = &[("expansion", "999999")];
+
+    type Input = Image;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Image, ParseError> {
+        Image::new(input)
+    }
+
+    fn part1(image: &Image, params: &Params) -> u64 {
+        total_distance(image, params.get("expansion"))
+    }
+
+    fn part2(image: &Image, params: &Params) -> u64 {
+        total_distance(image, params.get("expansion"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    aoc_runner::example_tests!(Day11);
+
+    #[test]
+    fn galaxy_pairs_are_correct_length() {
+        let content = fs::read_to_string("../input-example-1")
+            .expect("Should have been able to read the file");
+        let universe = Universe::new(&Image::new(&content).unwrap(), 1);
+        assert_eq!(universe.galaxy_pairs().len(), 36);
+    }
+
+    #[test]
+    fn distances_are_correct_length() {
+        let content = fs::read_to_string("../input-example-1")
+            .expect("Should have been able to read the file");
+        let universe = Universe::new(&Image::new(&content).unwrap(), 1);
+        let galaxy_a = universe.galaxies[0];
+        let galaxy_b = universe.galaxies[1];
+        assert_eq!(universe.distance(&galaxy_a, &galaxy_b), 6);
+    }
+}
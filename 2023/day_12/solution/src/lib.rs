@@ -0,0 +1,198 @@
+use aoc_runner::parse::{self, ParseError};
+use aoc_runner::{Params, Solution};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SpringState {
+    Operational,
+    Damaged,
+}
+
+/// A row of the condition records, as folded in the input.
+#[derive(Debug)]
+pub struct Row {
+    state: Vec<Option<SpringState>>,
+    group_sizes: Vec<usize>,
+}
+
+impl Row {
+    fn from_line(line: &str) -> Result<Self, ParseError> {
+        let (state, group_sizes) = parse::split_once(line, line, " ")?;
+        Ok(Self {
+            state: state
+                .char_indices()
+                .map(|(i, c)| match c {
+                    '.' => Ok(Some(SpringState::Operational)),
+                    '#' => Ok(Some(SpringState::Damaged)),
+                    '?' => Ok(None),
+                    _ => Err(ParseError::at(
+                        line,
+                        &state[i..i + c.len_utf8()],
+                        "., # or ?",
+                    )),
+                })
+                .collect::<Result<_, _>>()?,
+            group_sizes: group_sizes
+                .split(',')
+                .map(|c| parse::number(line, c))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Spring {
+    state: Vec<Option<SpringState>>,
+    group_sizes: Vec<usize>,
+    possibilities_cache: HashMap<String, usize>,
+}
+
+impl Spring {
+    fn new(row: &Row, copies: usize) -> Self {
+        let mut state = row.state.clone();
+        state.push(None);
+        state = state.repeat(copies);
+        state.pop();
+        Self {
+            state,
+            group_sizes: row.group_sizes.repeat(copies),
+            possibilities_cache: HashMap::new(),
+        }
+    }
+
+    fn get_cache_key(
+        state: &[Option<SpringState>],
+        groups_to_check: Vec<usize>,
+        current_group_size: usize,
+    ) -> String {
+        format!(
+            "s{}g{}c{}",
+            state
+                .iter()
+                .map(|s| match s {
+                    None => "x",
+                    Some(SpringState::Operational) => ".",
+                    Some(SpringState::Damaged) => "#",
+                })
+                .collect::<String>(),
+            groups_to_check
+                .iter()
+                .map(|x| format!("{}-", x))
+                .collect::<String>(),
+            current_group_size
+        )
+    }
+
+    fn possibilities_count_recursive(
+        &mut self,
+        state: &[Option<SpringState>],
+        groups_to_check: Vec<usize>,
+        current_group_size: usize,
+    ) -> usize {
+        let cache_key = Self::get_cache_key(state, groups_to_check.clone(), current_group_size);
+
+        if let Some(possibilities) = self.possibilities_cache.get(&cache_key) {
+            return *possibilities;
+        }
+
+        if state.is_empty() {
+            let result = if groups_to_check.is_empty()
+                || (groups_to_check.len() == 1 && groups_to_check[0] == current_group_size)
+            {
+                1
+            } else {
+                0
+            };
+            self.possibilities_cache.insert(cache_key, result);
+            return result;
+        }
+
+        let mut possibilities = 0;
+        if !groups_to_check.is_empty()
+            && current_group_size < groups_to_check[0]
+            && state[0].unwrap_or(SpringState::Damaged) == SpringState::Damaged
+        {
+            possibilities += self.possibilities_count_recursive(
+                &state[1..],
+                groups_to_check.clone(),
+                current_group_size + 1,
+            );
+        }
+        if state[0].unwrap_or(SpringState::Operational) == SpringState::Operational {
+            possibilities += if current_group_size == 0 {
+                self.possibilities_count_recursive(&state[1..], groups_to_check.clone(), 0)
+            } else if !groups_to_check.is_empty() && groups_to_check[0] == current_group_size {
+                self.possibilities_count_recursive(&state[1..], groups_to_check[1..].to_vec(), 0)
+            } else {
+                0
+            };
+        }
+
+        self.possibilities_cache.insert(cache_key, possibilities);
+        possibilities
+    }
+
+    fn possibilities_count(&mut self) -> usize {
+        self.possibilities_count_recursive(&self.state.clone(), self.group_sizes.clone(), 0)
+    }
+}
+
+/// Returns the total number of arrangements of the rows, each unfolded into `copies` copies.
+fn arrangements(rows: &[Row], copies: usize) -> usize {
+    let mut springs: Vec<Spring> = rows.iter().map(|l| Spring::new(l, copies)).collect();
+    springs.iter_mut().map(|s| s.possibilities_count()).sum()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 12;
+
+    type Input = Vec<Row>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Row>, ParseError> {
+        parse::lines(input, Row::from_line)
+    }
+
+    fn part1(rows: &Vec<Row>, _params: &Params) -> usize {
+        arrangements(rows, 1)
+    }
+
+    fn part2(rows: &Vec<Row>, _params: &Params) -> usize {
+        arrangements(rows, 5)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_runner::example_tests!(Day12);
+
+    #[test]
+    fn custom_test_problem_1() {
+        let content = ".#...?....???? 1,1,3".to_string();
+        assert_eq!(Day12::solve_part1(&content), 2);
+    }
+
+    #[test]
+    fn custom_test_problem_1b() {
+        let content = "?###???????? 3,2,1".to_string();
+        assert_eq!(Day12::solve_part1(&content), 10);
+    }
+
+    #[test]
+    fn custom_test_problem_1c() {
+        let content = "#????? 2,1".to_string();
+        assert_eq!(Day12::solve_part1(&content), 3);
+    }
+
+    #[test]
+    fn custom_test_problem_2() {
+        let content = "???.### 1,1,3".to_string();
+        assert_eq!(Day12::solve_part2(&content), 1);
+    }
+}
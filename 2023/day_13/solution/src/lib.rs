@@ -0,0 +1,182 @@
+use aoc_grid::Grid;
+use aoc_runner::parse::ParseError;
+use aoc_runner::{Params, Solution};
+
+#[derive(Debug)]
+pub struct Pattern {
+    row_hashes: Vec<u64>,
+    col_hashes: Vec<u64>,
+    width: usize,
+    height: usize,
+}
+
+/// Hashes a row or column of rocks into the bits of a number.
+fn hash<'a>(rocks: impl Iterator<Item = &'a bool>) -> u64 {
+    rocks
+        .enumerate()
+        .filter(|(_, rock)| **rock)
+        .map(|(i, _)| 1 << i)
+        .sum()
+}
+
+impl Pattern {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let rocks = Grid::parse(
+            input,
+            |c| match c {
+                '#' => Some(true),
+                '.' => Some(false),
+                _ => None,
+            },
+            "# or .",
+        )?;
+        Ok(Self {
+            row_hashes: rocks.rows().map(|row| hash(row.iter())).collect(),
+            col_hashes: rocks.columns().map(hash).collect(),
+            width: rocks.width(),
+            height: rocks.height(),
+        })
+    }
+
+    fn check_symmetry(array: &[u64], size: usize, with_smudge: bool) -> Option<usize> {
+        for i in 0..(size - 1) {
+            let mut encountered_smudge = false;
+            'outer: for di in 1..size {
+                let a = i as isize + 1 - di as isize;
+                let b = i + di;
+                if a < 0 || b >= size {
+                    if !with_smudge || encountered_smudge {
+                        return Some(i + 1);
+                    }
+                    break;
+                }
+                if with_smudge {
+                    let cmp = array[a as usize] ^ array[b];
+                    let mut smudges = 0;
+                    for b in 0..64 {
+                        if cmp & (1 << b) != 0 {
+                            smudges += 1;
+                            if smudges > 1 {
+                                break 'outer;
+                            }
+                        }
+                    }
+                    if smudges == 1 {
+                        if encountered_smudge {
+                            break;
+                        }
+                        encountered_smudge = true;
+                    }
+                } else if array[a as usize] != array[b] {
+                    break;
+                }
+            }
+        }
+        None
+    }
+
+    fn horizontal_mirror(&self) -> usize {
+        Self::check_symmetry(&self.row_hashes, self.height, false).unwrap_or(0)
+    }
+
+    fn vertical_mirror(&self) -> usize {
+        Self::check_symmetry(&self.col_hashes, self.width, false).unwrap_or(0)
+    }
+
+    fn horizontal_smudged_mirror(&self) -> usize {
+        Self::check_symmetry(&self.row_hashes, self.height, true).unwrap_or(0)
+    }
+
+    fn vertical_smudged_mirror(&self) -> usize {
+        Self::check_symmetry(&self.col_hashes, self.width, true).unwrap_or(0)
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 13;
+
+    type Input = Vec<Pattern>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Pattern>, ParseError> {
+        input
+            .split("\n\n")
+            .map(|pattern| Pattern::new(pattern).map_err(|e| e.within(input, pattern)))
+            .collect()
+    }
+
+    fn part1(patterns: &Vec<Pattern>, _params: &Params) -> usize {
+        patterns
+            .iter()
+            .map(|g| {
+                let v = g.vertical_mirror();
+                let h = g.horizontal_mirror();
+                assert!(v == 0 || h == 0);
+                assert!(v != 0 || h != 0);
+                v + 100 * h
+            })
+            .sum()
+    }
+
+    fn part2(patterns: &Vec<Pattern>, _params: &Params) -> usize {
+        patterns
+            .iter()
+            .map(|g| {
+                let v = g.vertical_smudged_mirror();
+                let h = g.horizontal_smudged_mirror();
+                assert!(v == 0 || h == 0);
+                assert!(v != 0 || h != 0);
+                v + 100 * h
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_runner::example_tests!(Day13);
+
+    #[test]
+    fn problem_1_custom() {
+        let content = "###.##......##.##
+#.#.###....###.#.
+..#.###....###.#.
+###.##......##.##
+.#####.####.#####
+..#.##..##..##.#.
+.###..#....#..###
+..##...#..#...##.
+...##.######.##..
+..#.....##.....#.
+.....#.####.#...."
+            .to_string();
+        assert_eq!(Day13::solve_part1(&content), 9);
+    }
+
+    #[test]
+    fn problem_2_custom() {
+        let content = "###.#.###
+.#..####.
+.##.#.#..
+.##.#.#..
+.#..####.
+###.#.###
+.#.#.#.##
+######.##
+#.#....#.
+#.#....#.
+######.##
+.#.#.#.##
+###.#.###
+.#..####.
+.##.#...."
+            .to_string();
+        assert_eq!(Day13::solve_part2(&content), 900);
+    }
+}
@@ -0,0 +1,235 @@
+use aoc_grid::Grid;
+use aoc_runner::parse::ParseError;
+use aoc_runner::{Params, Solution};
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Tile {
+    Cube,
+    Rounded,
+    Empty,
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Tile::Cube => '#',
+            Tile::Rounded => 'O',
+            Tile::Empty => '.',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct Platform {
+    width: usize,
+    height: usize,
+    rocks: Grid<Tile>,
+    cache: HashMap<Grid<Tile>, u64>,
+}
+
+impl fmt::Debug for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.rocks)
+    }
+}
+
+impl Platform {
+    fn new(input: &str) -> Result<Platform, ParseError> {
+        let rocks = Grid::parse(
+            input,
+            |c| match c {
+                '#' => Some(Tile::Cube),
+                'O' => Some(Tile::Rounded),
+                '.' => Some(Tile::Empty),
+                _ => None,
+            },
+            "#, O or .",
+        )?;
+        Ok(Platform {
+            width: rocks.width(),
+            height: rocks.height(),
+            rocks,
+            cache: HashMap::new(),
+        })
+    }
+
+    fn roll_north(&mut self) {
+        for x in 0..self.width {
+            let mut current_empty_spot = 0;
+            for y in 0..self.height {
+                match self.rocks[(x, y)] {
+                    Tile::Cube if y < self.height - 1 => {
+                        current_empty_spot = y + 1;
+                    }
+                    Tile::Rounded => {
+                        assert!(current_empty_spot < self.height);
+                        self.rocks[(x, y)] = Tile::Empty;
+                        self.rocks[(x, current_empty_spot)] = Tile::Rounded;
+                        if current_empty_spot < self.height - 1 {
+                            current_empty_spot += 1;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn roll_south(&mut self) {
+        for x in 0..self.width {
+            let mut current_empty_spot = self.height - 1;
+            for y in (0..self.height).rev() {
+                match self.rocks[(x, y)] {
+                    Tile::Cube if y > 0 => {
+                        current_empty_spot = y - 1;
+                    }
+                    Tile::Rounded => {
+                        self.rocks[(x, y)] = Tile::Empty;
+                        self.rocks[(x, current_empty_spot)] = Tile::Rounded;
+                        current_empty_spot = current_empty_spot.saturating_sub(1);
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn roll_west(&mut self) {
+        for y in 0..self.height {
+            let mut current_empty_spot = 0;
+            for x in 0..self.width {
+                match self.rocks[(x, y)] {
+                    Tile::Cube if x < self.width - 1 => {
+                        current_empty_spot = x + 1;
+                    }
+                    Tile::Rounded => {
+                        assert!(current_empty_spot < self.width);
+                        self.rocks[(x, y)] = Tile::Empty;
+                        self.rocks[(current_empty_spot, y)] = Tile::Rounded;
+                        if current_empty_spot < self.width - 1 {
+                            current_empty_spot += 1;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn roll_east(&mut self) {
+        for y in 0..self.height {
+            let mut current_empty_spot = self.width - 1;
+            for x in (0..self.width).rev() {
+                match self.rocks[(x, y)] {
+                    Tile::Cube if x > 0 => {
+                        current_empty_spot = x - 1;
+                    }
+                    Tile::Rounded => {
+                        self.rocks[(x, y)] = Tile::Empty;
+                        self.rocks[(current_empty_spot, y)] = Tile::Rounded;
+                        current_empty_spot = current_empty_spot.saturating_sub(1);
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn cycle(&mut self, iterations: u64) {
+        for cycles in 1..=iterations {
+            self.roll_north();
+            self.roll_west();
+            self.roll_south();
+            self.roll_east();
+
+            let seen_rocks = self.cache.get(&self.rocks);
+            if let Some(previous_cycles) = seen_rocks {
+                // Found loop
+                let loop_length = cycles - previous_cycles;
+                let iterations_left = iterations - cycles;
+                if iterations_left.is_multiple_of(loop_length) {
+                    break;
+                }
+            }
+
+            self.cache.insert(self.rocks.clone(), cycles);
+        }
+    }
+
+    fn load_north(&self) -> u64 {
+        self.rocks
+            .iter()
+            .map(|((_, y), rock)| match rock {
+                Tile::Rounded => (self.height - y) as u64,
+                _ => 0,
+            })
+            .sum()
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 14;
+    const PART2_PARAMS: &'static [(&'static str, &'static str)] = &[("cycles", "1000000000")];
+
+    type Input = Platform;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Platform, ParseError> {
+        Platform::new(input)
+    }
+
+    fn part1(platform: &Platform, _params: &Params) -> u64 {
+        let mut platform = platform.clone();
+        platform.roll_north();
+        platform.load_north()
+    }
+
+    fn part2(platform: &Platform, params: &Params) -> u64 {
+        let mut platform = platform.clone();
+        platform.cycle(params.get("cycles"));
+        platform.load_north()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_runner::example_tests!(Day14);
+
+    #[test]
+    fn problem_2_custom() {
+        let content = ".....
+.###.
+.#OO.
+.###.
+....."
+            .to_string();
+        let platform = Day14::parse(&content).unwrap();
+        for (cycles, load) in [("1", 6), ("2", 4), ("3", 2), ("9", 2)] {
+            let params = Params::new(&[("cycles", cycles)]);
+            assert_eq!(Day14::part2(&platform, &params), load);
+        }
+    }
+
+    #[test]
+    fn test_cycle() {
+        let content = ".....
+.###.
+.#..O
+.###.
+....O"
+            .to_string();
+        let mut platform = Platform::new(&content).unwrap();
+        assert_eq!(platform.load_north(), 4);
+        platform.cycle(1);
+        assert_eq!(platform.load_north(), 2);
+    }
+}
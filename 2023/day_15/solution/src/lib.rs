@@ -0,0 +1,126 @@
+use aoc_runner::parse::{self, ParseError};
+use aoc_runner::{Params, Solution};
+
+#[derive(Debug, Default, Clone)]
+struct Lens<'a> {
+    label: &'a str,
+    focal_length: u32,
+}
+
+fn hash(s: &str) -> u32 {
+    s.chars().fold(0, |acc, c| ((acc + c as u32) * 17) % 256)
+}
+
+/// What a step does with the lens of its label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation {
+    Remove,
+    Insert(u32),
+}
+
+/// A step of the initialization sequence, such as `rn=1` or `cm-`.
+#[derive(Debug)]
+pub struct Step {
+    text: String,
+    label: String,
+    operation: Operation,
+}
+
+fn focusing_power(steps: &[Step]) -> u64 {
+    let hashmap: Vec<Vec<Lens>> = steps.iter().fold(vec![vec![]; 256], |mut acc, step| {
+        let label = step.label.as_str();
+        if let Operation::Insert(focal_length) = step.operation {
+            // Add or replace lens in box
+            let lens = Lens {
+                label,
+                focal_length,
+            };
+            let index = hash(lens.label) as usize;
+
+            // Replace lens when it's already in the box
+            let mut already_in_box = false;
+            acc[index] = acc[index]
+                .iter()
+                .map(|l| {
+                    if l.label == lens.label {
+                        already_in_box = true;
+                        lens.clone()
+                    } else {
+                        l.clone()
+                    }
+                })
+                .collect();
+
+            // Add lens when it's not in the box
+            if !already_in_box {
+                acc[index].push(lens);
+            }
+        } else {
+            // Remove lens from box
+            let index = hash(label) as usize;
+            acc[index].retain(|l| l.label != label);
+        }
+        acc
+    });
+
+    hashmap
+        .iter()
+        .enumerate()
+        .flat_map(|(box_index, lenses)| {
+            lenses.iter().enumerate().map(move |(lens_index, lens)| {
+                (box_index as u64 + 1) * (lens_index as u64 + 1) * lens.focal_length as u64
+            })
+        })
+        .sum()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 15;
+
+    type Input = Vec<Step>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    /// Returns the steps, each of which removes a lens or sets a focal length.
+    fn parse(input: &str) -> Result<Vec<Step>, ParseError> {
+        input
+            .trim()
+            .split(',')
+            .map(|step| {
+                let (label, operation) = match step.strip_suffix('-') {
+                    Some(label) => (label, Operation::Remove),
+                    None => {
+                        let (label, focal_length) = parse::split_once(input, step, "=")?;
+                        (
+                            label,
+                            Operation::Insert(parse::number(input, focal_length)?),
+                        )
+                    }
+                };
+                Ok(Step {
+                    text: step.to_string(),
+                    label: label.to_string(),
+                    operation,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(steps: &Vec<Step>, _params: &Params) -> u64 {
+        steps.iter().map(|step| hash(&step.text) as u64).sum()
+    }
+
+    fn part2(steps: &Vec<Step>, _params: &Params) -> u64 {
+        focusing_power(steps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_runner::example_tests!(Day15);
+}
@@ -0,0 +1,192 @@
+use aoc_grid::geometry::Direction;
+use aoc_grid::{Grid, Position};
+use aoc_runner::parse::ParseError;
+use aoc_runner::{Params, Solution};
+use std::collections::HashSet;
+use std::fmt;
+
+#[derive(Debug, PartialEq, Hash, Copy, Clone)]
+enum Dot {
+    MirrorTopLeftBottomRight,
+    MirrorTopRightBottomLeft,
+    SplitterHorizontal,
+    SplitterVertical,
+}
+
+#[derive(Clone)]
+pub struct Contraption {
+    dots: Grid<Option<Dot>>,
+    dots_energized: HashSet<(Position, Direction)>,
+}
+
+impl fmt::Debug for Contraption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let energized = self.energized_points();
+        let tiles = Grid::from_fn(
+            self.dots.width(),
+            self.dots.height(),
+            |position| match self.dots[position] {
+                None if energized.contains(&position) => '#',
+                None => '.',
+                Some(Dot::MirrorTopLeftBottomRight) => '\\',
+                Some(Dot::MirrorTopRightBottomLeft) => '/',
+                Some(Dot::SplitterHorizontal) => '-',
+                Some(Dot::SplitterVertical) => '|',
+            },
+        );
+        write!(f, "{}", tiles)
+    }
+}
+
+impl Contraption {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let dots = Grid::parse(
+            input,
+            |c| match c {
+                '.' => Some(None),
+                '\\' => Some(Some(Dot::MirrorTopLeftBottomRight)),
+                '/' => Some(Some(Dot::MirrorTopRightBottomLeft)),
+                '-' => Some(Some(Dot::SplitterHorizontal)),
+                '|' => Some(Some(Dot::SplitterVertical)),
+                _ => None,
+            },
+            "a mirror, a splitter or .",
+        )?;
+        Ok(Self {
+            dots,
+            dots_energized: HashSet::new(),
+        })
+    }
+
+    fn travel_beam(&mut self, place: Position, direction: Direction) {
+        let mut vectors = vec![(place, direction)];
+
+        while !vectors.is_empty() {
+            let (place, direction) = vectors.remove(0);
+
+            let check_if_traveled_before = if let Some(dot) = self.dots[place] {
+                if dot == Dot::MirrorTopLeftBottomRight || dot == Dot::MirrorTopRightBottomLeft {
+                    // I'm too lazy to write the logic for this, so just skip the checks
+                    false
+                } else {
+                    true
+                }
+            } else {
+                true
+            };
+            if check_if_traveled_before && self.dots_energized.contains(&(place, direction)) {
+                continue;
+            }
+            self.dots_energized.insert((place, direction));
+
+            let directions: &[Direction] = match (direction, self.dots[place]) {
+                (Direction::North, None)
+                | (Direction::North, Some(Dot::SplitterVertical))
+                | (Direction::East, Some(Dot::MirrorTopRightBottomLeft))
+                | (Direction::West, Some(Dot::MirrorTopLeftBottomRight)) => &[Direction::North],
+                (Direction::South, None)
+                | (Direction::South, Some(Dot::SplitterVertical))
+                | (Direction::West, Some(Dot::MirrorTopRightBottomLeft))
+                | (Direction::East, Some(Dot::MirrorTopLeftBottomRight)) => &[Direction::South],
+                (Direction::East, None)
+                | (Direction::East, Some(Dot::SplitterHorizontal))
+                | (Direction::North, Some(Dot::MirrorTopRightBottomLeft))
+                | (Direction::South, Some(Dot::MirrorTopLeftBottomRight)) => &[Direction::East],
+                (Direction::West, None)
+                | (Direction::West, Some(Dot::SplitterHorizontal))
+                | (Direction::South, Some(Dot::MirrorTopRightBottomLeft))
+                | (Direction::North, Some(Dot::MirrorTopLeftBottomRight)) => &[Direction::West],
+                (Direction::North, Some(Dot::SplitterHorizontal))
+                | (Direction::South, Some(Dot::SplitterHorizontal)) => {
+                    &[Direction::West, Direction::East]
+                }
+                (Direction::East, Some(Dot::SplitterVertical))
+                | (Direction::West, Some(Dot::SplitterVertical)) => {
+                    &[Direction::North, Direction::South]
+                }
+            };
+            for direction in directions {
+                if let Some(next) = self.dots.step(place, *direction) {
+                    vectors.push((next, *direction));
+                }
+            }
+        }
+    }
+
+    fn energized_points(&self) -> HashSet<Position> {
+        self.dots_energized
+            .iter()
+            .fold(HashSet::new(), |mut acc, (pos, _)| {
+                acc.insert(*pos);
+                acc
+            })
+    }
+
+    fn energized(&self) -> u64 {
+        self.energized_points().len() as u64
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 16;
+
+    type Input = Contraption;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Contraption, ParseError> {
+        Contraption::new(input)
+    }
+
+    fn part1(contraption: &Contraption, _params: &Params) -> u64 {
+        let mut contraption = contraption.clone();
+        contraption.travel_beam((0, 0), Direction::East);
+        contraption.energized()
+    }
+
+    fn part2(contraption: &Contraption, _params: &Params) -> u64 {
+        let mut cases = vec![];
+
+        for direction in Direction::ALL {
+            let (width, height) = (contraption.dots.width(), contraption.dots.height());
+            for x in 0..width {
+                cases.push((x, 0, direction));
+                cases.push((x, height - 1, direction));
+            }
+            for y in 0..height {
+                cases.push((0, y, direction));
+                cases.push((width - 1, y, direction));
+            }
+        }
+
+        cases
+            .iter()
+            .map(|(x, y, direction)| {
+                let mut contraption = contraption.clone();
+                contraption.travel_beam((*x, *y), *direction);
+                contraption.energized()
+            })
+            .max()
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_runner::example_tests!(Day16);
+
+    #[test]
+    fn points_at_unexpected_characters() {
+        let input = ".|.\n.x.\n";
+        let error = Day16::parse(input).err().unwrap();
+        assert_eq!(
+            error.render(input),
+            "Invalid input at line 2, column 2: expected a mirror, a splitter or ., found \"x\"\n  |\n2 | .x.\n  |  ^"
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use aoc_grid::geometry::Direction;
+use aoc_grid::{Grid, Position};
+use aoc_runner::parse::ParseError;
+use aoc_runner::{Params, Solution};
+use aoc_search::dijkstra;
+
+/// A crucible on its way through the city, which has moved `moves` blocks in a straight line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Crucible {
+    position: Position,
+    direction: Direction,
+    moves: u8,
+}
+
+#[derive(Debug)]
+pub struct City {
+    /// The heat loss of each block.
+    blocks: Grid<u8>,
+}
+
+impl City {
+    fn new(input: &str) -> Result<City, ParseError> {
+        let blocks = Grid::parse(
+            input,
+            |c| c.to_digit(10).map(|heat_loss| heat_loss as u8),
+            "a digit",
+        )?;
+        Ok(City { blocks })
+    }
+
+    /// Returns where a crucible can move next, with the heat lost in the block it moves into. It
+    /// moves at most `max_moves` blocks in a straight line, and at least `min_moves` before it
+    /// turns or stops, but never turns back.
+    fn moves(&self, crucible: &Crucible, min_moves: u8, max_moves: u8) -> Vec<(Crucible, u64)> {
+        Direction::ALL
+            .into_iter()
+            .filter(|direction| {
+                if *direction == crucible.direction {
+                    crucible.moves < max_moves
+                } else {
+                    crucible.moves >= min_moves && *direction != crucible.direction.opposite()
+                }
+            })
+            .filter_map(|direction| {
+                let position = self.blocks.step(crucible.position, direction)?;
+                let moves = if direction == crucible.direction {
+                    crucible.moves + 1
+                } else {
+                    1
+                };
+                let crucible = Crucible {
+                    position,
+                    direction,
+                    moves,
+                };
+                Some((crucible, self.blocks[position] as u64))
+            })
+            .collect()
+    }
+
+    /// Find the path with the lowest heat loss and return the cumulative heat loss.
+    fn best_path_heat_loss(&self, min_moves: u8, max_moves: u8) -> u64 {
+        let end = (self.blocks.width() - 1, self.blocks.height() - 1);
+        let starts = Direction::ALL.map(|direction| Crucible {
+            position: (0, 0),
+            direction,
+            moves: 0,
+        });
+        let search = dijkstra(
+            starts,
+            |crucible| self.moves(crucible, min_moves, max_moves),
+            |crucible| crucible.position == end && crucible.moves >= min_moves,
+        );
+        search.path.expect("Could not find route").cost
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 17;
+    const PART1_PARAMS: &'static [(&'static str, &'static str)] =
+        &[("min_moves", "0"), ("max_moves", "3")];
+    const PART2_PARAMS: &'static [(&'static str, &'static str)] =
+        &[("min_moves", "4"), ("max_moves", "10")];
+
+    type Input = City;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<City, ParseError> {
+        City::new(input)
+    }
+
+    fn part1(city: &City, params: &Params) -> u64 {
+        city.best_path_heat_loss(params.get("min_moves"), params.get("max_moves"))
+    }
+
+    fn part2(city: &City, params: &Params) -> u64 {
+        city.best_path_heat_loss(params.get("min_moves"), params.get("max_moves"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_runner::example_tests!(Day17);
+}
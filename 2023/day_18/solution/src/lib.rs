@@ -0,0 +1,145 @@
+use aoc_grid::geometry::{Direction, Point};
+use aoc_runner::parse::{self, ParseError};
+use aoc_runner::{Params, Solution};
+
+#[derive(Debug)]
+struct Step {
+    direction: Direction,
+    length: u64,
+}
+
+impl Step {
+    fn from_line(line: &str) -> Result<Self, ParseError> {
+        let (direction, rest) = parse::split_once(line, line, " ")?;
+        let mut chars = direction.chars();
+        let direction = match (chars.next().and_then(Direction::from_char), chars.next()) {
+            (Some(direction), None) => direction,
+            _ => return Err(ParseError::at(line, direction, "one of R, L, U, D")),
+        };
+        let (length, _) = parse::split_once(line, rest, " ")?;
+        let length = parse::number(line, length)?;
+        Ok(Self { direction, length })
+    }
+
+    fn from_color(line: &str) -> Result<Self, ParseError> {
+        let color = line.rsplit(' ').next().unwrap_or_default();
+        let hex = color
+            .strip_prefix('(')
+            .and_then(|c| c.strip_suffix(')'))
+            .unwrap_or(color)
+            .strip_prefix('#')
+            .filter(|hex| hex.len() == 6 && hex.is_ascii())
+            .ok_or_else(|| ParseError::at(line, color, "a color such as (#70c710)"))?;
+        let length = u64::from_str_radix(&hex[..5], 16)
+            .map_err(|_| ParseError::at(line, &hex[..5], "a hexadecimal length"))?;
+        let direction = match &hex[5..] {
+            "0" => Direction::East,
+            "1" => Direction::South,
+            "2" => Direction::West,
+            "3" => Direction::North,
+            x => return Err(ParseError::at(line, x, "one of 0, 1, 2, 3")),
+        };
+        Ok(Self { direction, length })
+    }
+}
+
+pub struct Grid {
+    steps: Vec<Step>,
+}
+
+impl Grid {
+    fn new(steps: Vec<Step>) -> Self {
+        Self { steps }
+    }
+
+    /// Returns the area of the lagoon: the interior, which follows from the area of the polygon
+    /// through the centers of the trench by the shoelace formula and Pick's theorem, plus the
+    /// trench itself.
+    fn area(&self) -> u64 {
+        let mut corner = Point::new(0, 0);
+        let mut double_area = 0;
+        let mut trench = 0;
+        for step in &self.steps {
+            let next = corner + step.direction.delta() * step.length as i64;
+            double_area += corner.x * next.y - next.x * corner.y;
+            trench += step.length;
+            corner = next;
+        }
+        double_area.unsigned_abs() / 2 + trench / 2 + 1
+    }
+}
+
+/// The dig plan, read both as plain instructions and from the hexadecimal color codes.
+pub struct DigPlan {
+    grid: Grid,
+    color_grid: Grid,
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 18;
+
+    type Input = DigPlan;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<DigPlan, ParseError> {
+        Ok(DigPlan {
+            grid: Grid::new(parse::lines(input, Step::from_line)?),
+            color_grid: Grid::new(parse::lines(input, Step::from_color)?),
+        })
+    }
+
+    fn part1(plan: &DigPlan, _params: &Params) -> u64 {
+        plan.grid.area()
+    }
+
+    fn part2(plan: &DigPlan, _params: &Params) -> u64 {
+        plan.color_grid.area()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_runner::example_tests!(Day18);
+
+    #[test]
+    fn problem_1_custom() {
+        // ... ... 6
+        // . ... . 7
+        // .     . 7
+        // ....... 7
+        let content = "R 2 #000000
+D 1 #000000
+R 2 #000000
+U 1 #000000
+R 2 #000000
+D 3 #000000
+L 6 #000000
+U 3 #000000"
+            .to_string();
+        assert_eq!(Day18::solve_part1(&content), 3 * 7 + 6);
+    }
+
+    #[test]
+    fn problem_1_custom_2() {
+        // ......
+        // . .. .
+        // .    .
+        // ......
+        let content = "R 2 #000000
+D 1 #000000
+R 1 #000000
+U 1 #000000
+R 2 #000000
+D 3 #000000
+L 5 #000000
+U 3 #000000"
+            .to_string();
+        assert_eq!(Day18::solve_part1(&content), 4 * 6);
+    }
+}
@@ -0,0 +1,304 @@
+use aoc_interval::cuboid::Cuboid;
+use aoc_interval::Interval;
+use aoc_runner::parse::{self, ParseError};
+use aoc_runner::{Params, Solution};
+use std::collections::HashMap;
+
+/// The categories of ratings, in the order of the axes of a `MachinePartRange`.
+#[derive(Debug, Clone, Copy)]
+enum MachinePartType {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl MachinePartType {
+    /// Parses `text`, a slice of `line`.
+    fn parse(line: &str, text: &str) -> Result<Self, ParseError> {
+        match text {
+            "x" => Ok(Self::X),
+            "m" => Ok(Self::M),
+            "a" => Ok(Self::A),
+            "s" => Ok(Self::S),
+            x => Err(ParseError::at(line, x, "one of x, m, a, s")),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Rule {
+    GreaterThan(MachinePartType, i64, String),
+    LessThan(MachinePartType, i64, String),
+    Equals(MachinePartType, i64, String),
+    Workflow(String),
+}
+
+impl Rule {
+    /// Parses `s`, a slice of `line`.
+    fn parse(line: &str, s: &str) -> Result<Self, ParseError> {
+        let Some((condition, workflow)) = s.split_once(':') else {
+            return Ok(Rule::Workflow(s.to_string()));
+        };
+        let Some(i) = condition.find(['<', '>', '=']) else {
+            return Err(ParseError::at(
+                line,
+                condition,
+                "a condition such as a<2006",
+            ));
+        };
+        let machine_part_type = MachinePartType::parse(line, &condition[..i])?;
+        let value: i64 = parse::number(line, &condition[i + 1..])?;
+        let workflow = workflow.to_string();
+
+        Ok(match &condition[i..=i] {
+            ">" => Rule::GreaterThan(machine_part_type, value, workflow),
+            "<" => Rule::LessThan(machine_part_type, value, workflow),
+            _ => Rule::Equals(machine_part_type, value, workflow),
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Workflow {
+    name: String,
+    rules: Vec<Rule>,
+}
+
+impl Workflow {
+    fn parse(line: &str) -> Result<Self, ParseError> {
+        let (name, rules) = parse::split_once(line, line, "{")?;
+        let rules = rules
+            .strip_suffix('}')
+            .ok_or_else(|| ParseError::at(line, &line[line.len()..], "\"}\""))?;
+        let rules: Vec<Rule> = rules
+            .split(',')
+            .map(|rule| Rule::parse(line, rule))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            name: name.to_string(),
+            rules,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct MachinePartSpec {
+    machine_part_type: MachinePartType,
+    value: i64,
+}
+
+/// The ratings of machine parts, with an axis per category.
+type MachinePartRange = Cuboid<4>;
+
+fn full() -> MachinePartRange {
+    Cuboid::new([Interval::from(1..=4000); 4])
+}
+
+/// Parses a string representing a machine part range of size 1; a single machine part
+fn parse_single(line: &str) -> Result<MachinePartRange, ParseError> {
+    let s = line
+        .strip_prefix('{')
+        .and_then(|s| s.strip_suffix('}'))
+        .ok_or_else(|| ParseError::at(line, line, "ratings such as {x=1,m=2,a=3,s=4}"))?;
+    let specs: Vec<MachinePartSpec> = s
+        .split(',')
+        .map(|s| {
+            let (machine_part_type, value) = parse::split_once(line, s, "=")?;
+            Ok(MachinePartSpec {
+                machine_part_type: MachinePartType::parse(line, machine_part_type)?,
+                value: parse::number(line, value)?,
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    let mut ratings = [None; 4];
+    for spec in &specs {
+        ratings[spec.machine_part_type as usize] = Some(spec.value);
+    }
+    let mut point = [0; 4];
+    for ((rating, value), name) in point.iter_mut().zip(ratings).zip(["x", "m", "a", "s"]) {
+        *rating = value.ok_or_else(|| {
+            ParseError::at(
+                line,
+                &line[line.len() - 1..],
+                format!("a rating for {}", name),
+            )
+        })?;
+    }
+    Ok(Cuboid::point(point))
+}
+
+fn execute_workflow(
+    range: &MachinePartRange,
+    workflow: &Workflow,
+) -> Vec<(String, MachinePartRange)> {
+    let mut split_ranges = vec![];
+    let mut remaining_ranges = vec![*range];
+    for rule in &workflow.rules {
+        let mut new_remaining_ranges = vec![];
+        match rule {
+            Rule::GreaterThan(machine_part_type, value, next_workflow_name) => {
+                for remaining_range in remaining_ranges {
+                    let (low, high) =
+                        remaining_range.split_at(*machine_part_type as usize, value + 1);
+                    new_remaining_ranges.extend(low);
+                    split_ranges.extend(high.map(|high| (next_workflow_name.clone(), high)));
+                }
+            }
+            Rule::LessThan(machine_part_type, value, next_workflow_name) => {
+                for remaining_range in remaining_ranges {
+                    let (low, high) = remaining_range.split_at(*machine_part_type as usize, *value);
+                    split_ranges.extend(low.map(|low| (next_workflow_name.clone(), low)));
+                    new_remaining_ranges.extend(high);
+                }
+            }
+            Rule::Equals(machine_part_type, value, next_workflow_name) => {
+                let axis = *machine_part_type as usize;
+                for remaining_range in remaining_ranges {
+                    let (low, rest) = remaining_range.split_at(axis, *value);
+                    let (mid, high) =
+                        rest.map_or((None, None), |rest| rest.split_at(axis, value + 1));
+                    new_remaining_ranges.extend(low);
+                    split_ranges.extend(mid.map(|mid| (next_workflow_name.clone(), mid)));
+                    new_remaining_ranges.extend(high);
+                }
+            }
+            Rule::Workflow(next_workflow_name) => {
+                for remaining_range in remaining_ranges {
+                    split_ranges.push((next_workflow_name.clone(), remaining_range));
+                }
+            }
+        }
+        remaining_ranges = new_remaining_ranges;
+        if remaining_ranges.is_empty() {
+            break;
+        }
+    }
+    split_ranges
+}
+
+fn total_accepted_parts(range: &MachinePartRange, workflows: &HashMap<String, Workflow>) -> u64 {
+    let mut ranges: Vec<(String, MachinePartRange)> = vec![("in".to_string(), *range)];
+    let mut accepted_count = 0;
+
+    while !ranges.is_empty() {
+        let (workflow_name, range) = ranges.remove(0);
+        let workflow = workflows.get(&workflow_name).unwrap();
+        let new_ranges: Vec<(String, MachinePartRange)> = execute_workflow(&range, workflow)
+            .into_iter()
+            .filter(|(workflow_name, range)| {
+                if *workflow_name == "A" {
+                    accepted_count += range.volume();
+                    false
+                } else {
+                    *workflow_name != "R"
+                }
+            })
+            .collect();
+        ranges.extend(new_ranges);
+    }
+
+    accepted_count
+}
+
+/// Checks that the parts start at a workflow named `in`, and that every rule in `text`, the
+/// workflows of `input`, sends them to a workflow that exists or to A or R.
+fn check_references(
+    input: &str,
+    text: &str,
+    workflows: &HashMap<String, Workflow>,
+) -> Result<(), ParseError> {
+    if !workflows.contains_key("in") {
+        return Err(ParseError::at(input, &input[..0], "a workflow named in"));
+    }
+    for line in text.lines() {
+        let Some((_, rules)) = line.split_once('{') else {
+            continue;
+        };
+        for rule in rules.trim_end_matches('}').split(',') {
+            let next_workflow_name = rule.rsplit(':').next().unwrap_or(rule);
+            if !matches!(next_workflow_name, "A" | "R")
+                && !workflows.contains_key(next_workflow_name)
+            {
+                return Err(ParseError::at(
+                    input,
+                    next_workflow_name,
+                    "the name of a workflow, A or R",
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug)]
+pub struct System {
+    workflows: HashMap<String, Workflow>,
+    machine_parts: Vec<MachinePartRange>,
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 19;
+
+    type Input = System;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<System, ParseError> {
+        let (workflows, machine_parts) = input.split_once("\n\n").unwrap_or((input, ""));
+        let system = System {
+            workflows: HashMap::from_iter(
+                parse::lines(workflows, Workflow::parse)?
+                    .into_iter()
+                    .map(|workflow| (workflow.name.clone(), workflow)),
+            ),
+            machine_parts: parse::lines(machine_parts, parse_single)
+                .map_err(|e| e.within(input, machine_parts))?,
+        };
+        check_references(input, workflows, &system.workflows)?;
+        Ok(system)
+    }
+
+    fn part1(system: &System, _params: &Params) -> u64 {
+        system
+            .machine_parts
+            .iter()
+            .filter(|part| {
+                let value = total_accepted_parts(part, &system.workflows);
+                assert!(value <= 1);
+                value == 1
+            })
+            .map(|part| part.axes.iter().map(|rating| rating.start).sum::<i64>() as u64)
+            .sum()
+    }
+
+    fn part2(system: &System, _params: &Params) -> u64 {
+        total_accepted_parts(&full(), &system.workflows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_runner::example_tests!(Day19);
+
+    #[test]
+    fn rejects_undefined_workflows() {
+        let error = Day19::parse("in{a<2006:qkq,A}\n\n{x=1,m=2,a=3,s=4}\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 11: expected the name of a workflow, A or R, found \"qkq\""
+        );
+        let error = Day19::parse("px{A}\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected a workflow named in, found nothing"
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use aoc_runner::parse::{self, ParseError};
+use aoc_runner::{Params, Solution};
+use std::cmp::max;
+
+#[derive(Debug)]
+pub struct Game {
+    id: u32,
+    min_green: u32,
+    min_red: u32,
+    min_blue: u32,
+}
+
+impl Game {
+    fn from_line(line: &str) -> Result<Self, ParseError> {
+        let (game, cases) = parse::split_once(line, line, ": ")?;
+        let id = match game.strip_prefix("Game ") {
+            Some(id) => parse::number(line, id)?,
+            None => return Err(ParseError::at(line, game, "Game <id>")),
+        };
+
+        let mut min_green = 0;
+        let mut min_blue = 0;
+        let mut min_red = 0;
+
+        for case in cases.split(";") {
+            for color_case in case.split(",") {
+                let color_case = color_case.trim();
+                let (count, color) = parse::split_once(line, color_case, " ")?;
+                let count = parse::number::<u32>(line, count)?;
+                match color {
+                    "red" => {
+                        min_red = max(min_red, count);
+                    }
+                    "green" => {
+                        min_green = max(min_green, count);
+                    }
+                    "blue" => {
+                        min_blue = max(min_blue, count);
+                    }
+                    _ => return Err(ParseError::at(line, color, "red, green or blue")),
+                }
+            }
+        }
+
+        Ok(Self {
+            id,
+            min_green,
+            min_red,
+            min_blue,
+        })
+    }
+
+    fn is_possible(&self, red: u32, green: u32, blue: u32) -> bool {
+        self.min_red <= red && self.min_green <= green && self.min_blue <= blue
+    }
+
+    fn power(&self) -> u32 {
+        self.min_red * self.min_green * self.min_blue
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+    const PART1_PARAMS: &'static [(&'static str, &'static str)] =
+        &[("red", "12"), ("green", "13"), ("blue", "14")];
+
+    type Input = Vec<Game>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+        parse::lines(input, Game::from_line)
+    }
+
+    fn part1(games: &Vec<Game>, params: &Params) -> u32 {
+        let red = params.get("red");
+        let green = params.get("green");
+        let blue = params.get("blue");
+        games
+            .iter()
+            .filter(|x| x.is_possible(red, green, blue))
+            .map(|x| x.id)
+            .sum::<u32>()
+    }
+
+    fn part2(games: &Vec<Game>, _params: &Params) -> u32 {
+        games.iter().map(|x| x.power()).sum::<u32>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_runner::example_tests!(Day2);
+}
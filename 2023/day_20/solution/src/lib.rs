@@ -0,0 +1,210 @@
+use aoc_runner::parse::{self, ParseError};
+use aoc_runner::{Params, Solution};
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Pulse {
+    Low,
+    High,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ModuleType {
+    FlipFlop,
+    Conjuction,
+    Broadcaster,
+}
+
+#[derive(Debug, Clone)]
+pub struct Module {
+    module_type: ModuleType,
+    name: String,
+    destination_modules: Vec<String>,
+    on: bool,
+    inputs: HashMap<String, Pulse>,
+}
+
+impl Module {
+    fn from_line(line: &str) -> Result<Self, ParseError> {
+        let (module, destinations) = parse::split_once(line, line, " -> ")?;
+        let (module_type, name) = if let Some(name) = module.strip_prefix('%') {
+            (ModuleType::FlipFlop, name)
+        } else if let Some(name) = module.strip_prefix('&') {
+            (ModuleType::Conjuction, name)
+        } else if module == "broadcaster" {
+            (ModuleType::Broadcaster, module)
+        } else {
+            return Err(ParseError::at(
+                line,
+                module,
+                "a module such as %a, &b or broadcaster",
+            ));
+        };
+        Ok(Self {
+            module_type,
+            name: name.to_string(),
+            destination_modules: destinations.split(", ").map(|s| s.to_string()).collect(),
+            on: false,
+            inputs: HashMap::new(),
+        })
+    }
+}
+
+fn send_pulse(modules: &mut HashMap<String, Module>) -> (u64, u64) {
+    let mut low_count = 1;
+    let mut high_count = 0;
+    let mut pulse_queue = vec![("button".to_string(), "broadcaster".to_string(), Pulse::Low)];
+    while !pulse_queue.is_empty() {
+        let (_source, destination, pulse) = pulse_queue.remove(0);
+        // NOTE: _source is for debugging purposes, remove when done.
+        if !modules.contains_key(&destination) {
+            continue;
+        }
+        match modules.get(&destination).unwrap().module_type {
+            ModuleType::FlipFlop => {
+                if pulse == Pulse::Low {
+                    let (pulse_out, destinations) = {
+                        let module = modules.get_mut(&destination).unwrap();
+                        module.on = !module.on;
+                        let pulse_out = if module.on {
+                            high_count += module.destination_modules.len() as u64;
+                            Pulse::High
+                        } else {
+                            low_count += module.destination_modules.len() as u64;
+                            Pulse::Low
+                        };
+                        (pulse_out, module.destination_modules.clone())
+                    };
+                    for new_destination in destinations {
+                        if let Some(destination_module) = modules.get_mut(&new_destination) {
+                            destination_module
+                                .inputs
+                                .insert(destination.clone(), pulse_out.clone());
+                        }
+                        pulse_queue.push((
+                            destination.clone(),
+                            new_destination.clone(),
+                            pulse_out.clone(),
+                        ));
+                    }
+                }
+            }
+            ModuleType::Conjuction => {
+                let (pulse_out, destinations) = {
+                    let module = modules.get_mut(&destination).unwrap();
+                    module.on = pulse == Pulse::High;
+                    let all_inputs_high = module.inputs.values().all(|p| *p == Pulse::High);
+                    let pulse_out = if all_inputs_high {
+                        low_count += module.destination_modules.len() as u64;
+                        Pulse::Low
+                    } else {
+                        high_count += module.destination_modules.len() as u64;
+                        Pulse::High
+                    };
+                    (pulse_out, module.destination_modules.clone())
+                };
+                for new_destination in destinations {
+                    if let Some(destination_module) = modules.get_mut(&new_destination) {
+                        destination_module
+                            .inputs
+                            .insert(destination.clone(), pulse_out.clone());
+                    }
+                    pulse_queue.push((
+                        destination.clone(),
+                        new_destination.clone(),
+                        pulse_out.clone(),
+                    ))
+                }
+            }
+            ModuleType::Broadcaster => {
+                let destinations = {
+                    let module = modules.get_mut(&destination).unwrap();
+                    if pulse == Pulse::Low {
+                        low_count += module.destination_modules.len() as u64;
+                    } else {
+                        high_count += module.destination_modules.len() as u64;
+                    }
+                    module.destination_modules.clone()
+                };
+                for new_destination in destinations {
+                    if let Some(destination_module) = modules.get_mut(&new_destination) {
+                        destination_module
+                            .inputs
+                            .insert(destination.clone(), pulse.clone());
+                    }
+                    pulse_queue.push((destination.clone(), new_destination.clone(), pulse.clone()))
+                }
+            }
+        }
+    }
+
+    (low_count, high_count)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 20;
+
+    type Input = HashMap<String, Module>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<HashMap<String, Module>, ParseError> {
+        let mut modules: HashMap<String, Module> = HashMap::from_iter(
+            parse::lines(input, Module::from_line)?
+                .into_iter()
+                .map(|module| (module.name.clone(), module)),
+        );
+        // Set the initial inputs for each conjuction
+        let destinations_map: Vec<(String, Vec<String>)> = {
+            modules
+                .values()
+                .map(|m| (m.name.clone(), m.destination_modules.clone()))
+                .collect()
+        };
+        for (module, destinations) in destinations_map {
+            for destination in destinations {
+                if let Some(destination_module) = modules.get_mut(&destination) {
+                    destination_module.inputs.insert(module.clone(), Pulse::Low);
+                }
+            }
+        }
+        Ok(modules)
+    }
+
+    fn part1(modules: &HashMap<String, Module>, _params: &Params) -> u64 {
+        let mut modules = modules.clone();
+        let mut cumulative_low_count = 0;
+        let mut cumulative_high_count = 0;
+        for _ in 0..1000 {
+            let (low_count, high_count) = send_pulse(&mut modules);
+            cumulative_low_count += low_count;
+            cumulative_high_count += high_count;
+        }
+        cumulative_low_count * cumulative_high_count
+    }
+
+    fn part2(_modules: &HashMap<String, Module>, _params: &Params) -> u64 {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_runner::example_tests!(Day20);
+
+    #[test]
+    fn rejects_unknown_modules() {
+        let error = Day20::parse("broadcaster -> a\n%a -> b\n!b -> a\n")
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: expected a module such as %a, &b or broadcaster, found \"!b\""
+        );
+    }
+}
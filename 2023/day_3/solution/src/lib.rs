@@ -0,0 +1,104 @@
+use aoc_grid::{Grid, Position};
+use aoc_runner::parse::ParseError;
+use aoc_runner::{Params, Solution};
+
+#[derive(Debug)]
+pub enum Spot {
+    Nothing,
+    Digit(u8),
+    Symbol,
+}
+
+pub type Map = Grid<Spot>;
+
+fn find_symbol_locations(map: &Map) -> Vec<Position> {
+    map.iter()
+        .filter(|(_, spot)| matches!(spot, Spot::Symbol))
+        .map(|(position, _)| position)
+        .collect()
+}
+
+fn find_adjacent_numbers(map: &Map, position: Position) -> Vec<u32> {
+    // Find the beginning of each number next to the position, once
+    let mut starts = vec![];
+    for (x, y) in map.neighbors8(position) {
+        let row = map.row(y);
+        if !matches!(row[x], Spot::Digit(_)) {
+            continue;
+        }
+        let start = (0..=x)
+            .rev()
+            .take_while(|&x| matches!(row[x], Spot::Digit(_)))
+            .last()
+            .unwrap();
+        if !starts.contains(&(start, y)) {
+            starts.push((start, y));
+        }
+    }
+
+    starts
+        .into_iter()
+        .map(|(start, y)| {
+            map.row(y)[start..]
+                .iter()
+                .map_while(|spot| match spot {
+                    Spot::Digit(digit) => Some(*digit as u32),
+                    _ => None,
+                })
+                .fold(0, |number, digit| number * 10 + digit)
+        })
+        .collect()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+
+    type Input = Map;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        Grid::parse(
+            input,
+            |c| {
+                Some(match c {
+                    '.' => Spot::Nothing,
+                    '0'..='9' => Spot::Digit(c.to_digit(10).unwrap() as u8),
+                    _ => Spot::Symbol,
+                })
+            },
+            "a part of the schematic",
+        )
+    }
+
+    fn part1(map: &Map, _params: &Params) -> u32 {
+        find_symbol_locations(map)
+            .into_iter()
+            .map(|position| find_adjacent_numbers(map, position).iter().sum::<u32>())
+            .sum()
+    }
+
+    fn part2(map: &Map, _params: &Params) -> u32 {
+        find_symbol_locations(map)
+            .into_iter()
+            .map(|position| {
+                let adjacent_numbers = find_adjacent_numbers(map, position);
+                if adjacent_numbers.len() > 1 {
+                    adjacent_numbers.iter().product()
+                } else {
+                    0
+                }
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_runner::example_tests!(Day3);
+}
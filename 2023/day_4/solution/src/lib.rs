@@ -0,0 +1,95 @@
+use aoc_runner::parse::{self, ParseError};
+use aoc_runner::{Params, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub struct Card {
+    winning_numbers: HashSet<u32>,
+    numbers: Vec<u32>,
+}
+
+impl Card {
+    fn from_line(line: &str) -> Result<Card, ParseError> {
+        let (game, data) = parse::split_once(line, line, ": ")?;
+        if !game.starts_with("Card ") {
+            return Err(ParseError::at(line, game, "Card <id>"));
+        }
+
+        let (winning_numbers_str, numbers_str) = parse::split_once(line, data, " | ")?;
+
+        let winning_numbers = winning_numbers_str
+            .split(' ')
+            .filter(|x| !x.is_empty())
+            .map(|x| parse::number(line, x))
+            .collect::<Result<_, _>>()?;
+
+        let numbers = numbers_str
+            .split(' ')
+            .filter(|x| !x.is_empty())
+            .map(|x| parse::number(line, x))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Card {
+            winning_numbers,
+            numbers,
+        })
+    }
+
+    fn wins(&self) -> usize {
+        self.numbers
+            .iter()
+            .filter(|x| self.winning_numbers.contains(x))
+            .count()
+    }
+
+    fn score(&self) -> u32 {
+        match self.wins() {
+            0 => 0_u32,
+            n => 2_i32.pow((n - 1) as u32) as u32,
+        }
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+
+    type Input = Vec<Card>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+        parse::lines(input, Card::from_line)
+    }
+
+    fn part1(cards: &Vec<Card>, _params: &Params) -> u32 {
+        cards.iter().map(Card::score).sum()
+    }
+
+    fn part2(cards: &Vec<Card>, _params: &Params) -> u32 {
+        let mut copies = HashMap::new();
+
+        let mut total_cards = 0;
+
+        for (i, card) in cards.iter().enumerate() {
+            let number_of_cards = copies.get(&i).unwrap_or(&0) + 1;
+
+            let wins = card.wins();
+
+            total_cards += number_of_cards;
+            for j in 0..wins {
+                let cards_to_add = copies.get(&(i + j + 1)).unwrap_or(&0) + number_of_cards;
+                copies.insert(i + j + 1, cards_to_add);
+            }
+        }
+        total_cards
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_runner::example_tests!(Day4);
+}
@@ -0,0 +1,197 @@
+use aoc_interval::map::OffsetMap;
+use aoc_interval::{Interval, IntervalSet};
+use aoc_runner::parse::{self, ParseError};
+use aoc_runner::{Params, Solution};
+
+#[derive(Debug)]
+enum ParseState {
+    /// The first line, which lists the seeds.
+    Seeds,
+    /// After the seeds, where a map header should come.
+    Header,
+    SeedToSoil,
+    SoilToFertilizer,
+    FertilizerToWater,
+    WaterToLight,
+    LightToTemperature,
+    TemperatureToHumidity,
+    HumidityToLocation,
+}
+
+#[derive(Debug, Default)]
+pub struct Almanak {
+    seeds: Vec<i64>,
+    seed_to_soil: OffsetMap,
+    soil_to_fertilizer: OffsetMap,
+    fertilizer_to_water: OffsetMap,
+    water_to_light: OffsetMap,
+    light_to_temperature: OffsetMap,
+    temperature_to_humidity: OffsetMap,
+    humidity_to_location: OffsetMap,
+}
+
+impl Almanak {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let mut state = ParseState::Seeds;
+        let mut almanak = Almanak::default();
+        for line in input.lines().filter(|x| !x.is_empty()) {
+            // Set next state
+            match line {
+                "seed-to-soil map:" => {
+                    state = ParseState::SeedToSoil;
+                    continue;
+                }
+                "soil-to-fertilizer map:" => {
+                    state = ParseState::SoilToFertilizer;
+                    continue;
+                }
+                "fertilizer-to-water map:" => {
+                    state = ParseState::FertilizerToWater;
+                    continue;
+                }
+                "water-to-light map:" => {
+                    state = ParseState::WaterToLight;
+                    continue;
+                }
+                "light-to-temperature map:" => {
+                    state = ParseState::LightToTemperature;
+                    continue;
+                }
+                "temperature-to-humidity map:" => {
+                    state = ParseState::TemperatureToHumidity;
+                    continue;
+                }
+                "humidity-to-location map:" => {
+                    state = ParseState::HumidityToLocation;
+                    continue;
+                }
+                _ => {}
+            }
+
+            // Parse line
+            let map = match state {
+                ParseState::Seeds => {
+                    let (_, seeds_str) = parse::split_once(input, line, ": ")?;
+                    almanak.seeds = seeds_str
+                        .split(' ')
+                        .map(|x| parse::number(input, x))
+                        .collect::<Result<_, _>>()?;
+                    // Part 2 reads them as pairs of a range start and length
+                    if almanak.seeds.len() % 2 != 0 {
+                        return Err(ParseError::at(input, line, "pairs of seed numbers"));
+                    }
+                    state = ParseState::Header;
+                    continue;
+                }
+                ParseState::Header => return Err(ParseError::at(input, line, "a map header")),
+                ParseState::SeedToSoil => &mut almanak.seed_to_soil,
+                ParseState::SoilToFertilizer => &mut almanak.soil_to_fertilizer,
+                ParseState::FertilizerToWater => &mut almanak.fertilizer_to_water,
+                ParseState::WaterToLight => &mut almanak.water_to_light,
+                ParseState::LightToTemperature => &mut almanak.light_to_temperature,
+                ParseState::TemperatureToHumidity => &mut almanak.temperature_to_humidity,
+                ParseState::HumidityToLocation => &mut almanak.humidity_to_location,
+            };
+            let inputs: Vec<i64> = line
+                .split(' ')
+                .map(|x| parse::number(input, x))
+                .collect::<Result<_, _>>()?;
+            if inputs.len() != 3 {
+                return Err(ParseError::at(input, line, "three numbers"));
+            }
+            let (destination, source, length) = (inputs[0], inputs[1], inputs[2]);
+            map.insert(Interval::with_len(source, length), destination - source);
+        }
+        if almanak.seeds.is_empty() {
+            let first_line = input.lines().next().unwrap_or(input);
+            return Err(ParseError::at(input, first_line, "pairs of seed numbers"));
+        }
+        Ok(almanak)
+    }
+
+    /// Returns every seed on its own.
+    fn single_seeds(&self) -> IntervalSet {
+        self.seeds
+            .iter()
+            .map(|seed| Interval::with_len(*seed, 1))
+            .collect()
+    }
+
+    /// Returns the seeds as pairs of a range start and length.
+    fn seed_ranges(&self) -> IntervalSet {
+        self.seeds
+            .chunks(2)
+            .map(|chunk| Interval::with_len(chunk[0], chunk[1]))
+            .collect()
+    }
+
+    /// Returns the lowest location number for the given seeds.
+    fn lowest_location_for_seeds(&self, seeds: IntervalSet) -> u64 {
+        let seed_to_location = [
+            &self.seed_to_soil,
+            &self.soil_to_fertilizer,
+            &self.fertilizer_to_water,
+            &self.water_to_light,
+            &self.light_to_temperature,
+            &self.temperature_to_humidity,
+            &self.humidity_to_location,
+        ]
+        .into_iter()
+        .fold(OffsetMap::new(), |map, next| map.then(next));
+
+        seed_to_location.apply_to_set(&seeds).min().unwrap() as u64
+    }
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
+
+    type Input = Almanak;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Almanak, ParseError> {
+        Almanak::new(input)
+    }
+
+    fn part1(almanak: &Almanak, _params: &Params) -> u64 {
+        almanak.lowest_location_for_seeds(almanak.single_seeds())
+    }
+
+    fn part2(almanak: &Almanak, _params: &Params) -> u64 {
+        almanak.lowest_location_for_seeds(almanak.seed_ranges())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_runner::example_tests!(Day5);
+
+    #[test]
+    fn rejects_maps_without_header() {
+        let error = Day5::parse("seeds: 79 14\n\n50 98 2\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: expected a map header, found \"50 98 2\""
+        );
+    }
+
+    #[test]
+    fn rejects_seeds_without_pairs() {
+        let error = Day5::parse("seeds: 79 14 55\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected pairs of seed numbers, found \"seeds: 79 14 55\""
+        );
+        let error = Day5::parse("seed-to-soil map:\n50 98 2\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected pairs of seed numbers, found \"seed-to-soil map:\""
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use aoc_runner::parse::{self, ParseError};
+use aoc_runner::{Params, Solution};
+use std::cmp::{max, min};
+
+#[derive(Debug)]
+struct Race {
+    time: u64,            // ms
+    record_distance: u64, // mm
+}
+
+impl Race {
+    /// Returns the number of possible games that win.
+    ///
+    /// A boat reaches a certain distance in the given time:
+    ///
+    /// distance(holding_time) = time_left * speed
+    ///                        = (time - holding_time) * speed
+    ///                        = (time - holding_time) * holding_time
+    ///                        = -holding_time^2 + (time * holding_time)
+    ///
+    /// or:
+    ///
+    /// f(x) = -x^2 + Tx
+    ///      = W
+    ///
+    /// Assuming there are winners (i.e. distance > record_distance), there should be two
+    /// intersections on `f(x) = W`, where `W = record_distance`.
+    ///
+    /// We have to calculate the two intersections and count the number of whole numbers
+    /// in the x-axis between them.
+    ///
+    /// x^2 - Tx + W = 0            x: holding_time, T: race.time, W: race.record_distance
+    /// ax^2 + bx + c = 0 => a = 1, b = -T, c = W
+    ///
+    /// The quadratic formula gives us two solutions:
+    ///
+    /// x1, x2 = (-b +- sqrt(b^2 - 4ac)) / 2a
+    ///        = (T +- sqrt(T^2 - 4W)) / 2
+    ///
+    fn wins_count(&self) -> u64 {
+        let winning_holding_time_min = (self.time as f64
+            - ((self.time * self.time - 4 * self.record_distance) as f64).sqrt())
+            / 2.0;
+        let winning_holding_time_max = (self.time as f64
+            + ((self.time * self.time - 4 * self.record_distance) as f64).sqrt())
+            / 2.0;
+
+        let mut _min = max(0, winning_holding_time_min.ceil() as u64);
+        let mut _max = min(self.time, winning_holding_time_max.floor() as u64);
+
+        if _min as f64 == winning_holding_time_min {
+            _min += 1;
+        }
+
+        if _max as f64 == winning_holding_time_max {
+            _max -= 1;
+        }
+
+        _max - _min + 1
+    }
+}
+
+/// The numbers on the time and distance lines.
+#[derive(Debug)]
+pub struct Sheet {
+    times: Vec<u64>,
+    distances: Vec<u64>,
+}
+
+/// Joins the digits of numbers into one, as if the spaces between them weren't there.
+fn join(numbers: &[u64]) -> u64 {
+    numbers.iter().fold(0, |joined, &number| {
+        let digits = number.checked_ilog10().unwrap_or(0) + 1;
+        joined * 10_u64.pow(digits) + number
+    })
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 6;
+
+    type Input = Sheet;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Sheet, ParseError> {
+        let mut data: Vec<Vec<u64>> = parse::lines(input, |line| {
+            let (_, numbers) = parse::split_once(line, line, ":")?;
+            numbers
+                .split(' ')
+                .filter(|x| !x.is_empty())
+                .map(|x| parse::number(line, x))
+                .collect()
+        })?;
+        if data.len() != 2 {
+            return Err(ParseError::at(
+                input,
+                input.trim_end(),
+                "a time and a distance line",
+            ));
+        }
+
+        let distances = data.pop().unwrap();
+        let times = data.pop().unwrap();
+        Ok(Sheet { times, distances })
+    }
+
+    fn part1(sheet: &Sheet, _params: &Params) -> u64 {
+        let races = sheet
+            .times
+            .iter()
+            .zip(sheet.distances.iter())
+            .map(|(&time, &record_distance)| Race {
+                time,
+                record_distance,
+            })
+            .collect::<Vec<Race>>();
+
+        races.iter().map(Race::wins_count).product()
+    }
+
+    fn part2(sheet: &Sheet, _params: &Params) -> u64 {
+        Race {
+            time: join(&sheet.times),
+            record_distance: join(&sheet.distances),
+        }
+        .wins_count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_runner::example_tests!(Day6);
+
+    #[test]
+    fn joins_digits() {
+        assert_eq!(join(&[7, 15, 30]), 71530);
+        assert_eq!(join(&[0, 9]), 9);
+        assert_eq!(join(&[]), 0);
+    }
+}
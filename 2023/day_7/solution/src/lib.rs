@@ -0,0 +1,232 @@
+use aoc_runner::parse::{self, ParseError};
+use aoc_runner::{Params, Solution};
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+static WILDCARD: u32 = 1;
+
+#[derive(Debug, PartialEq, PartialOrd)]
+enum HandType {
+    FiveOfAKind,
+    FourOfAKind,
+    FullHouse,
+    ThreeOfAKind,
+    TwoPair,
+    OnePair,
+    HighCard,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Hand {
+    cards: Vec<u32>,
+    bid: u32,
+}
+
+impl Hand {
+    fn from_line(line: &str) -> Result<Self, ParseError> {
+        let (cards, bid) = parse::split_once(line, line, " ")?;
+        Ok(Hand {
+            cards: cards
+                .char_indices()
+                .map(|(i, c)| match c {
+                    'A' => Ok(14),
+                    'K' => Ok(13),
+                    'Q' => Ok(12),
+                    'J' => Ok(11),
+                    'T' => Ok(10),
+                    '2'..='9' => Ok(c as u32 - '0' as u32),
+                    _ => Err(ParseError::at(line, &cards[i..i + c.len_utf8()], "a card")),
+                })
+                .collect::<Result<_, _>>()?,
+            bid: parse::number(line, bid)?,
+        })
+    }
+
+    /// Returns the hand with its jacks replaced by wildcards.
+    fn with_wildcards(&self) -> Self {
+        Hand {
+            cards: self
+                .cards
+                .iter()
+                .map(|card| if *card == 11 { WILDCARD } else { *card })
+                .collect(),
+            bid: self.bid,
+        }
+    }
+
+    /// Returns the type of hand, 1 being the lowest (high card)
+    fn hand_type(&self) -> HandType {
+        let mut groups: [u32; 15] = [0; 15];
+        let mut seen_cards = HashSet::new();
+        let mut number_of_wildcards = 0;
+
+        for card in &self.cards {
+            if *card == WILDCARD {
+                number_of_wildcards += 1;
+            } else {
+                let index = *card as usize;
+                groups[index] += 1;
+                seen_cards.insert(index);
+            }
+        }
+
+        // Append number_of_wildcards to the cards
+        if seen_cards.is_empty() {
+            // All cards are wildcards
+            return HandType::FiveOfAKind;
+        }
+        let most_occuring_index = seen_cards
+            .iter()
+            .max_by(|a, b| groups[**a].cmp(&groups[**b]))
+            .unwrap();
+        groups[*most_occuring_index] += number_of_wildcards;
+        groups[WILDCARD as usize] = groups[*most_occuring_index];
+
+        let hand_type = match seen_cards.len() {
+            5 => HandType::HighCard,
+            4 => HandType::OnePair,
+            3 if groups[self.cards[0] as usize] == 1 && groups[self.cards[1] as usize] == 2 => {
+                HandType::TwoPair
+            }
+            3 if groups[self.cards[0] as usize] == 2 => HandType::TwoPair,
+            3 => HandType::ThreeOfAKind,
+            2 if groups[self.cards[0] as usize] == 2 => HandType::FullHouse,
+            2 if groups[self.cards[0] as usize] == 3 => HandType::FullHouse,
+            2 => HandType::FourOfAKind,
+            _ => HandType::FiveOfAKind,
+        };
+
+        assert!(!(number_of_wildcards > 0 && hand_type == HandType::HighCard));
+        assert!(!(number_of_wildcards > 0 && hand_type == HandType::TwoPair));
+        assert!(!(number_of_wildcards == 4 && hand_type != HandType::FiveOfAKind));
+
+        hand_type
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let hand_type = self.hand_type();
+        let other_hand_type = other.hand_type();
+
+        if hand_type < other_hand_type {
+            Ordering::Greater
+        } else if hand_type > other_hand_type {
+            Ordering::Less
+        } else {
+            for i in 0..self.cards.len() {
+                match self.cards[i].cmp(&other.cards[i]) {
+                    Ordering::Equal => continue,
+                    ord => return ord,
+                }
+            }
+            panic!("There should be no duplicate hands!")
+        }
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn total_winnings(mut hands: Vec<Hand>) -> u64 {
+    hands.sort();
+
+    let answer = hands
+        .iter()
+        .enumerate()
+        .map(|(i, hand)| (i + 1) as u64 * hand.bid as u64)
+        .sum();
+
+    // Check for wrong answers
+    assert_ne!(answer, 249272171);
+
+    answer
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 7;
+
+    type Input = Vec<Hand>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<Hand>, ParseError> {
+        parse::lines(input, Hand::from_line)
+    }
+
+    fn part1(hands: &Vec<Hand>, _params: &Params) -> u64 {
+        total_winnings(hands.clone())
+    }
+
+    fn part2(hands: &Vec<Hand>, _params: &Params) -> u64 {
+        total_winnings(hands.iter().map(Hand::with_wildcards).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_runner::example_tests!(Day7);
+
+    #[test]
+    fn problem_2_solved_custom_test() {
+        let content = "
+2345A 2
+2345J 5
+J345A 3
+32T3K 7
+T55J5 17
+KK677 11
+KTJJT 23
+QQQJA 19
+JJJJJ 29
+JAAAA 37
+AAAAJ 43
+AAAAA 53
+2AAAA 13
+2JJJJ 41
+JJJJ2 31
+"
+        .to_string();
+        assert_eq!(Day7::solve_part2(&content), 3667);
+    }
+
+    #[test]
+    fn problem_2_solved_custom_test_2() {
+        let content = "
+AAAAA 2
+22222 3
+AAAAK 5
+22223 7
+AAAKK 11
+22233 13
+AAAKQ 17
+22234 19
+AAKKQ 23
+22334 29
+AAKQJ 31
+22345 37
+AKQJT 41
+23456 43
+"
+        .to_string();
+        assert_eq!(Day7::solve_part2(&content), 1369);
+    }
+
+    #[test]
+    fn problem_2_regression_test() {
+        let content = "
+JAAKK 1
+JJJAK 2
+"
+        .to_string();
+        assert_eq!(Day7::solve_part2(&content), 5);
+    }
+}
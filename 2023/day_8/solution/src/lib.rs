@@ -0,0 +1,249 @@
+use aoc_number_theory::crt;
+use aoc_runner::parse::{self, ParseError};
+use aoc_runner::{Params, Solution};
+use std::collections::HashMap;
+
+/// The steps at which a ghost is at a node ending in Z. After `cycle_start` steps, it walks the
+/// same cycle of `cycle_length` steps forever, so each end it reaches within the first cycle comes
+/// back once per cycle.
+#[derive(Debug)]
+struct Ghost {
+    ends: Vec<u64>,
+    cycle_start: u64,
+    cycle_length: u64,
+}
+
+impl Ghost {
+    fn is_at_end(&self, steps: u64) -> bool {
+        if steps < self.cycle_start {
+            self.ends.contains(&steps)
+        } else {
+            self.ends
+                .iter()
+                .any(|&end| end >= self.cycle_start && end.abs_diff(steps) % self.cycle_length == 0)
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Network {
+    directions: Vec<char>,
+    nodes: Vec<String>,
+    left_map: HashMap<String, String>,
+    right_map: HashMap<String, String>,
+}
+
+impl Network {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let mut nodes = vec![];
+        let mut left_map = HashMap::new();
+        let mut right_map = HashMap::new();
+        let mut references = vec![];
+
+        let first_line = input.lines().next().unwrap_or_default();
+        if let Some(i) = first_line.find(|c| c != 'L' && c != 'R') {
+            return Err(ParseError::at(input, &first_line[i..=i], "L or R"));
+        }
+        let directions = first_line.chars().collect::<Vec<char>>();
+        if directions.is_empty() {
+            return Err(ParseError::at(input, first_line, "L or R"));
+        }
+        for line in input.lines().skip(2).filter(|line| !line.is_empty()) {
+            let (id, children) = parse::split_once(input, line, " = (")?;
+            let (left, right) = parse::split_once(input, children, ", ")?;
+            let Some(right) = right.strip_suffix(')') else {
+                return Err(ParseError::at(input, &right[right.len()..], "\")\""));
+            };
+
+            left_map.insert(id.to_string(), left.to_string());
+            right_map.insert(id.to_string(), right.to_string());
+            nodes.push(id.to_string());
+            references.extend([left, right]);
+        }
+        if let Some(reference) = references
+            .into_iter()
+            .find(|reference| !left_map.contains_key(*reference))
+        {
+            return Err(ParseError::at(input, reference, "a defined node"));
+        }
+
+        Ok(Self {
+            directions,
+            nodes,
+            left_map,
+            right_map,
+        })
+    }
+
+    /// Returns the node reached from `current` after the given number of steps were taken.
+    fn next<'a>(&'a self, current: &str, steps: u64) -> &'a String {
+        let index = (steps % self.directions.len() as u64) as usize;
+        match self.directions[index] {
+            'L' => self.left_map.get(current).unwrap(),
+            'R' => self.right_map.get(current).unwrap(),
+            _ => panic!("Invalid direction"),
+        }
+    }
+
+    /// Walks from `start` until the ghost is at the same node at the same point in the
+    /// directions as before, after which it goes round in circles.
+    fn trace(&self, start: &str) -> Ghost {
+        let mut seen = HashMap::new();
+        let mut ends = vec![];
+        let mut steps: u64 = 0;
+        let mut current = start;
+        loop {
+            let instruction = steps % self.directions.len() as u64;
+            if let Some(cycle_start) = seen.insert((current, instruction), steps) {
+                return Ghost {
+                    ends,
+                    cycle_start,
+                    cycle_length: steps - cycle_start,
+                };
+            }
+            if current.ends_with('Z') {
+                ends.push(steps);
+            }
+            current = self.next(current, steps);
+            steps += 1;
+        }
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 8;
+
+    type Input = Network;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Network, ParseError> {
+        Network::new(input)
+    }
+
+    fn part1(network: &Network, _params: &Params) -> u64 {
+        // Traverse the graph
+        let mut steps: u64 = 0;
+        let mut current = "AAA";
+        while current != "ZZZ" {
+            current = network.next(current, steps);
+            steps += 1;
+        }
+        steps
+    }
+
+    fn part2(network: &Network, _params: &Params) -> u64 {
+        let ghosts: Vec<Ghost> = network
+            .nodes
+            .iter()
+            .filter(|id| id.ends_with('A'))
+            .map(|id| network.trace(id))
+            .collect();
+
+        // Before every ghost is in its cycle, check each step. Without ghosts, they are all at an
+        // end right away.
+        let cycles_start = ghosts
+            .iter()
+            .map(|ghost| ghost.cycle_start)
+            .max()
+            .unwrap_or(0);
+        if let Some(steps) =
+            (0..cycles_start).find(|&steps| ghosts.iter().all(|ghost| ghost.is_at_end(steps)))
+        {
+            return steps;
+        }
+
+        // After that, merge the congruences of the ends within the cycles one ghost at a time,
+        // keeping only the solutions that agree with every ghost so far
+        let mut solutions = vec![(0, 1)];
+        for ghost in &ghosts {
+            let congruences: Vec<(i128, i128)> = ghost
+                .ends
+                .iter()
+                .filter(|&&end| end >= ghost.cycle_start)
+                .map(|&end| (end as i128, ghost.cycle_length as i128))
+                .collect();
+            solutions = solutions
+                .iter()
+                .flat_map(|&solution| {
+                    congruences
+                        .iter()
+                        .filter_map(move |&congruence| crt([solution, congruence]))
+                })
+                .collect();
+            solutions.sort_unstable();
+            solutions.dedup();
+        }
+        solutions
+            .into_iter()
+            .map(|(steps, period)| {
+                // The first solution once every ghost is in its cycle
+                let behind = (cycles_start as i128 - steps).max(0);
+                (steps + (behind + period - 1) / period * period) as u64
+            })
+            .min()
+            .expect("The ghosts are never at an end node at the same time")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_runner::example_tests!(Day8);
+
+    #[test]
+    fn test_2_4_gives_4() {
+        let content = "L
+
+AAA = (AAB, XXX)
+AAB = (AAZ, XXX)
+AAZ = (AAB, XXX)
+BBA = (BBB, XXX)
+BBB = (BBC, XXX)
+BBC = (BBD, XXX)
+BBD = (BBZ, XXX)
+BBZ = (BBB, XXX)
+XXX = (XXX, XXX)
+"
+        .to_string();
+        assert_eq!(Day8::solve_part2(&content), 4);
+    }
+
+    #[test]
+    fn ends_can_come_back_sooner_than_first_reached() {
+        // The first ghost is at an end after 3, 5, 7... steps, the second after 2, 5, 8...
+        let content = "L
+
+AAA = (AAB, XXX)
+AAB = (AAC, XXX)
+AAC = (AAZ, XXX)
+AAZ = (AAC, XXX)
+BBA = (BBB, XXX)
+BBB = (BBZ, XXX)
+BBZ = (BBC, XXX)
+BBC = (BBB, XXX)
+XXX = (XXX, XXX)
+"
+        .to_string();
+        assert_eq!(Day8::solve_part2(&content), 5);
+    }
+
+    #[test]
+    fn no_ghosts_take_no_steps() {
+        let content = "L\n\nAAB = (AAZ, AAB)\nAAZ = (AAB, AAZ)\n".to_string();
+        assert_eq!(Day8::solve_part2(&content), 0);
+    }
+
+    #[test]
+    fn rejects_undefined_nodes() {
+        let error = Day8::parse("L\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 8: expected a defined node, found \"BBB\""
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use aoc_runner::parse::{self, ParseError};
+use aoc_runner::{Params, Solution};
+
+#[derive(Debug)]
+pub struct Sequence {
+    numbers: Vec<i64>,
+}
+
+impl Sequence {
+    fn new(data: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            numbers: data
+                .split(' ')
+                .map(|x| parse::number(data, x))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn prediction_recursive(numbers: Vec<i64>) -> i64 {
+        let mut new_numbers = vec![];
+        let mut all_zeroes = true;
+        let mut last_number = None;
+
+        for number in numbers.iter() {
+            if let Some(last_number) = last_number {
+                let new_number = number - last_number;
+                if new_number != 0 {
+                    all_zeroes = false;
+                }
+                new_numbers.push(new_number);
+            }
+            last_number = Some(*number);
+        }
+        if all_zeroes {
+            *numbers.last().unwrap()
+        } else {
+            numbers.last().unwrap() + Self::prediction_recursive(new_numbers)
+        }
+    }
+
+    fn predict_next(&self) -> i64 {
+        Self::prediction_recursive(self.numbers.clone())
+    }
+
+    fn predict_last(&self) -> i64 {
+        let mut numbers = self.numbers.clone();
+        numbers.reverse();
+        Self::prediction_recursive(numbers)
+    }
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 9;
+
+    type Input = Vec<Sequence>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Sequence>, ParseError> {
+        parse::lines(input, Sequence::new)
+    }
+
+    fn part1(sequences: &Vec<Sequence>, _params: &Params) -> i64 {
+        sequences.iter().map(|x| x.predict_next()).sum()
+    }
+
+    fn part2(sequences: &Vec<Sequence>, _params: &Params) -> i64 {
+        sequences.iter().map(|x| x.predict_last()).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_runner::example_tests!(Day9);
+}
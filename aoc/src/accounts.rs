@@ -0,0 +1,211 @@
+//! Solves a day against the inputs of several accounts, kept in `inputs/<account>/` next to its
+//! own input, each with an `answers.toml`.
+
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_runner::input::Source;
+use aoc_runner::{Day, Part};
+
+use crate::answers::Answers;
+use crate::batch::{Run, Status};
+
+/// The directory of a day that holds a directory per account.
+pub const DIR: &str = "inputs";
+
+/// Returns the name and directory of every account with an input for the day in `dir`, sorted by
+/// name.
+pub fn list(dir: &Path) -> Result<Vec<(String, PathBuf)>, String> {
+    let inputs = dir.join(DIR);
+    let entries =
+        fs::read_dir(&inputs).map_err(|e| format!("Could not read {}: {}", inputs.display(), e))?;
+    let mut accounts: Vec<(String, PathBuf)> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.join("input").is_file())
+        .filter_map(|path| Some((path.file_name()?.to_string_lossy().into_owned(), path)))
+        .collect();
+    accounts.sort();
+    Ok(accounts)
+}
+
+/// Returns the source of the input of the account in `dir`.
+pub fn source(dir: &Path) -> Source {
+    Source::File(dir.join("input"))
+}
+
+/// How the answer of an account's part compares with its recorded answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// The part was solved, but no answer was recorded for the account.
+    Unchecked(String),
+    /// The part wasn't solved, usually because the input breaks an assumption of the solution.
+    Broken(Status),
+}
+
+impl Outcome {
+    /// Compares what became of a part with its recorded answer, if there is one.
+    pub fn new(status: &Status, expected: Option<&str>) -> Self {
+        match (status, expected) {
+            (Status::Solved(actual), Some(expected)) if actual == expected => Outcome::Pass,
+            (Status::Solved(actual), Some(expected)) => Outcome::Fail {
+                expected: expected.to_string(),
+                actual: actual.clone(),
+            },
+            (Status::Solved(actual), None) => Outcome::Unchecked(actual.clone()),
+            (status, _) => Outcome::Broken(status.clone()),
+        }
+    }
+
+    fn label(&self) -> &str {
+        match self {
+            Outcome::Pass => "pass",
+            Outcome::Fail { .. } => "FAIL",
+            Outcome::Unchecked(_) => "unchecked",
+            Outcome::Broken(status) => status.label(),
+        }
+    }
+
+    fn detail(&self) -> Option<String> {
+        match self {
+            Outcome::Pass => None,
+            Outcome::Fail { expected, actual } => {
+                Some(format!("expected {}, got {}", expected, actual))
+            }
+            Outcome::Unchecked(actual) => {
+                Some(format!("got {}, but no answer is recorded", actual))
+            }
+            Outcome::Broken(status) => status.detail(),
+        }
+    }
+
+    /// Returns whether the part gave a wrong answer or wasn't solved.
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Outcome::Fail { .. } | Outcome::Broken(_))
+    }
+}
+
+/// The outcome of each part for an account.
+pub type Report = (String, [Outcome; 2]);
+
+/// Compares the runs of a day, a pair of parts per account in the order of `accounts`, with the
+/// answers recorded for each account.
+pub fn compare(accounts: &[(String, PathBuf)], runs: &[Run]) -> Result<Vec<Report>, String> {
+    accounts
+        .iter()
+        .zip(runs.chunks(2))
+        .map(|((name, dir), pair)| {
+            let answers = Answers::load(dir)?;
+            let outcomes = Part::ALL.map(|part| {
+                Outcome::new(&pair[part.number() as usize - 1].status, answers.get(part))
+            });
+            Ok((name.clone(), outcomes))
+        })
+        .collect()
+}
+
+/// Renders a matrix of the outcomes per account, followed by the details of the parts that
+/// didn't pass.
+pub fn render(day: &Day, reports: &[Report]) -> String {
+    let width = reports
+        .iter()
+        .map(|(name, _)| name.len())
+        .chain(["account".len()])
+        .max()
+        .unwrap_or_default()
+        + 2;
+    let mut matrix = format!("{:<width$}{:<11}part 2\n", "account", "part 1");
+    let mut details = String::new();
+    for (name, outcomes) in reports {
+        writeln!(
+            matrix,
+            "{:<width$}{:<11}{}",
+            name,
+            outcomes[0].label(),
+            outcomes[1].label()
+        )
+        .unwrap();
+        for (outcome, part) in outcomes.iter().zip(Part::ALL) {
+            if let Some(detail) = outcome.detail() {
+                writeln!(
+                    details,
+                    "{} day {} part {} for {}: {}",
+                    day.year, day.day, part, name, detail
+                )
+                .unwrap();
+            }
+        }
+    }
+    if !details.is_empty() {
+        matrix.push('\n');
+        matrix.push_str(&details);
+    }
+    matrix
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn lists_accounts_with_an_input() {
+        let dir = tempfile::tempdir().unwrap();
+        for account in ["work", "home", "empty"] {
+            fs::create_dir_all(dir.path().join(DIR).join(account)).unwrap();
+        }
+        fs::write(dir.path().join("inputs/work/input"), "1\n").unwrap();
+        fs::write(dir.path().join("inputs/home/input"), "2\n").unwrap();
+        let names: Vec<String> = list(dir.path())
+            .unwrap()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(names, ["home", "work"]);
+    }
+
+    #[test]
+    fn compares_runs_with_answers_per_account() {
+        let dir = tempfile::tempdir().unwrap();
+        let (home, work) = (dir.path().join("home"), dir.path().join("work"));
+        fs::create_dir_all(&home).unwrap();
+        fs::create_dir_all(&work).unwrap();
+        fs::write(home.join("answers.toml"), "part1 = 6\npart2 = 6\n").unwrap();
+        fs::write(work.join("answers.toml"), "part1 = 7\n").unwrap();
+        let accounts = [("home".to_string(), home), ("work".to_string(), work)];
+
+        let run = |part, status| Run {
+            year: 2023,
+            day: 8,
+            part,
+            status,
+            duration: Duration::ZERO,
+        };
+        let runs = [
+            run(Part::One, Status::Solved("6".to_string())),
+            run(Part::Two, Status::Solved("6".to_string())),
+            run(Part::One, Status::Solved("8".to_string())),
+            run(Part::Two, Status::Panicked("No cycle".to_string())),
+        ];
+        let reports = compare(&accounts, &runs).unwrap();
+        assert_eq!(reports[0].1, [Outcome::Pass, Outcome::Pass]);
+        assert!(reports[1].1.iter().all(Outcome::is_failure));
+
+        let day = crate::days::find(2023, 8).unwrap();
+        assert_eq!(
+            render(day, &reports),
+            "account  part 1     part 2\n\
+             home     pass       pass\n\
+             work     FAIL       PANIC\n\
+             \n\
+             2023 day 8 part 1 for work: expected 7, got 8\n\
+             2023 day 8 part 2 for work: panicked: No cycle\n"
+        );
+    }
+}
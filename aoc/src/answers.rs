@@ -0,0 +1,90 @@
+//! The accepted answers of a day, kept in `answers.toml` next to its input.
+
+use std::fs;
+use std::path::Path;
+
+use aoc_runner::manifest::Value;
+use aoc_runner::Part;
+use serde::{Deserialize, Serialize};
+
+pub const FILE_NAME: &str = "answers.toml";
+
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Answers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    part1: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    part2: Option<Value>,
+}
+
+impl Answers {
+    /// Loads the answers of the day in `dir`, which are empty when none were recorded.
+    pub fn load(dir: &Path) -> Result<Self, String> {
+        let path = dir.join(FILE_NAME);
+        match fs::read_to_string(&path) {
+            Ok(content) => toml::from_str(&content)
+                .map_err(|e| format!("Invalid answers {}: {}", path.display(), e)),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    pub fn save(&self, dir: &Path) -> Result<(), String> {
+        let path = dir.join(FILE_NAME);
+        let content = toml::to_string(self).expect("Answers should serialize");
+        fs::write(&path, content).map_err(|e| format!("Could not write {}: {}", path.display(), e))
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+        .map(|answer| answer.0.as_str())
+    }
+
+    pub fn set(&mut self, part: Part, answer: &str) {
+        let answer = Some(Value(answer.to_string()));
+        match part {
+            Part::One => self.part1 = answer,
+            Part::Two => self.part2 = answer,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_answers_per_part() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(Answers::load(dir.path()), Ok(Answers::default()));
+
+        let mut answers = Answers::default();
+        answers.set(Part::Two, "abc");
+        answers.set(Part::One, "42");
+        answers.save(dir.path()).unwrap();
+        assert_eq!(
+            fs::read_to_string(dir.path().join(FILE_NAME)).unwrap(),
+            "part1 = 42\npart2 = \"abc\"\n"
+        );
+        let answers = Answers::load(dir.path()).unwrap();
+        assert_eq!(answers.get(Part::One), Some("42"));
+        assert_eq!(answers.get(Part::Two), Some("abc"));
+    }
+
+    #[test]
+    fn keeps_answers_that_only_look_like_numbers() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut answers = Answers::default();
+        answers.set(Part::One, "0123");
+        answers.set(Part::Two, "+5");
+        answers.save(dir.path()).unwrap();
+        assert_eq!(
+            fs::read_to_string(dir.path().join(FILE_NAME)).unwrap(),
+            "part1 = \"0123\"\npart2 = \"+5\"\n"
+        );
+        assert_eq!(Answers::load(dir.path()), Ok(answers));
+    }
+}
@@ -0,0 +1,500 @@
+//! Solves many days at once, each part in a child process with limits on its time and memory,
+//! and summarizes their answers.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::io::{self, Read, Write};
+use std::os::unix::process::{CommandExt, ExitStatusExt};
+use std::panic::{self, AssertUnwindSafe};
+use std::process::{Command, ExitCode, ExitStatus, Stdio};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc_runner::parse::ParseError;
+use aoc_runner::{Day, Params, Part};
+
+use crate::bench::format_nanos;
+
+/// The exit code of a child process whose part panicked, as for an uncaught panic.
+const PANIC_EXIT_CODE: u8 = 101;
+/// The exit code of a child process that couldn't parse its input.
+const INVALID_INPUT_EXIT_CODE: u8 = 2;
+
+/// What became of a part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Solved(String),
+    Panicked(String),
+    /// The part took longer than the timeout, and was killed.
+    TimedOut,
+    /// The part needed more memory than it was allowed.
+    OutOfMemory,
+    /// The part couldn't be solved, such as when its input is invalid.
+    Failed(String),
+    NoInput,
+}
+
+impl Status {
+    pub fn label(&self) -> &str {
+        match self {
+            Status::Solved(answer) => answer,
+            Status::Panicked(_) => "PANIC",
+            Status::TimedOut => "TIMEOUT",
+            Status::OutOfMemory => "OOM",
+            Status::Failed(_) => "ERROR",
+            Status::NoInput => "no input",
+        }
+    }
+
+    /// Describes what went wrong, unless the part was solved or has no input.
+    pub fn detail(&self) -> Option<String> {
+        match self {
+            Status::Panicked(message) => Some(format!("panicked: {}", message)),
+            Status::TimedOut => Some("timed out".to_string()),
+            Status::OutOfMemory => Some("ran out of memory".to_string()),
+            Status::Failed(message) => Some(message.clone()),
+            Status::Solved(_) | Status::NoInput => None,
+        }
+    }
+
+    /// Returns whether the part went wrong, as opposed to being solved or having no input.
+    pub fn is_failure(&self) -> bool {
+        self.detail().is_some()
+    }
+}
+
+/// The limits a part is solved within.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// How long the part may take, including starting its process and parsing the input.
+    pub timeout: Duration,
+    /// The size of the address space of the part's process in bytes, if it's limited.
+    pub memory: Option<u64>,
+}
+
+/// A day to solve.
+pub struct Job {
+    pub day: &'static Day,
+    /// The normalized input and the parameters of each part, or what became of both parts when
+    /// the day can't be started.
+    pub setup: Result<(String, [Params; 2]), Status>,
+}
+
+/// The outcome of a part, and how long it took. The time of part 1 includes parsing the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub status: Status,
+    pub duration: Duration,
+}
+
+pub(crate) fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
+
+/// Solves a part against the input on stdin, as the child process of a batch. Prints the time
+/// it took in nanoseconds and the answer on separate lines, or the reason it failed to stderr.
+pub fn solve_part(day: &Day, part: Part, params: &Params) -> ExitCode {
+    let mut input = String::new();
+    if let Err(e) = io::stdin().read_to_string(&mut input) {
+        eprintln!("Could not read stdin: {}", e);
+        return ExitCode::FAILURE;
+    }
+    // The message is reported to the batch instead
+    panic::set_hook(Box::new(|_| {}));
+    let solved = panic::catch_unwind(AssertUnwindSafe(|| -> Result<_, ParseError> {
+        let start = Instant::now();
+        let parsed = day.parse(&input)?;
+        // Only part 1 counts the time it takes to parse, as when a day is solved at once
+        let start = if part == Part::One {
+            start
+        } else {
+            Instant::now()
+        };
+        let answer = day.solve_part(parsed.as_ref(), part, params);
+        Ok((answer, start.elapsed()))
+    }));
+    match solved {
+        Ok(Ok((answer, duration))) => {
+            println!("{}\n{}", duration.as_nanos(), answer);
+            ExitCode::SUCCESS
+        }
+        Ok(Err(e)) => {
+            eprintln!("invalid input at {}", e);
+            ExitCode::from(INVALID_INPUT_EXIT_CODE)
+        }
+        Err(payload) => {
+            eprintln!("{}", panic_message(payload));
+            ExitCode::from(PANIC_EXIT_CODE)
+        }
+    }
+}
+
+/// Limits the address space of the current process, to be called in a child before it starts.
+fn limit_memory(bytes: u64) -> io::Result<()> {
+    let limit = libc::rlimit {
+        rlim_cur: bytes as libc::rlim_t,
+        rlim_max: bytes as libc::rlim_t,
+    };
+    // SAFETY: setrlimit only reads the given struct, and is async-signal-safe
+    if unsafe { libc::setrlimit(libc::RLIMIT_AS, &limit) } == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+/// Reads a pipe of a child process to the end on another thread.
+fn read_pipe(mut pipe: impl Read + Send + 'static) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut content = String::new();
+        let _ = pipe.read_to_string(&mut content);
+        content
+    })
+}
+
+/// Tells what became of a part from how its process exited and what it printed.
+fn status_of(exit: ExitStatus, stdout: &str, stderr: &str) -> Result<(String, Duration), Status> {
+    let stderr = stderr.trim_end();
+    if exit.success() {
+        let (nanos, answer) = stdout
+            .split_once('\n')
+            .ok_or_else(|| Status::Failed(format!("unexpected output {:?}", stdout)))?;
+        let nanos = nanos
+            .parse()
+            .map_err(|_| Status::Failed(format!("unexpected output {:?}", stdout)))?;
+        return Ok((answer.trim_end().to_string(), Duration::from_nanos(nanos)));
+    }
+    // Rust aborts when an allocation fails, and the kernel kills processes when it runs out
+    if stderr.contains("memory allocation of") || exit.signal() == Some(libc::SIGKILL) {
+        return Err(Status::OutOfMemory);
+    }
+    Err(match exit.code() {
+        Some(code) if code == PANIC_EXIT_CODE as i32 => Status::Panicked(stderr.to_string()),
+        _ if !stderr.is_empty() => Status::Failed(stderr.to_string()),
+        _ => Status::Failed(format!("exited with {}", exit)),
+    })
+}
+
+/// Runs a part in a child process with the input on its stdin, killing it when it takes longer
+/// than the timeout.
+pub fn run_child(mut command: Command, input: &str, limits: &Limits) -> (Status, Duration) {
+    command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    if let Some(bytes) = limits.memory {
+        // SAFETY: limit_memory only calls setrlimit, which is safe to call after a fork
+        unsafe {
+            command.pre_exec(move || limit_memory(bytes));
+        }
+    }
+    let start = Instant::now();
+    let mut child = match command.spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            let status = Status::Failed(format!("Could not start {:?}: {}", command, e));
+            return (status, Duration::ZERO);
+        }
+    };
+    let mut stdin = child.stdin.take().expect("stdin should be piped");
+    let input = input.to_string();
+    // A part that doesn't read its input shouldn't block the batch
+    thread::spawn(move || stdin.write_all(input.as_bytes()));
+    let stdout = read_pipe(child.stdout.take().expect("stdout should be piped"));
+    let stderr = read_pipe(child.stderr.take().expect("stderr should be piped"));
+
+    let exit = loop {
+        match child.try_wait() {
+            Ok(Some(exit)) => break exit,
+            Ok(None) if start.elapsed() < limits.timeout => thread::sleep(Duration::from_millis(5)),
+            Ok(None) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return (Status::TimedOut, limits.timeout);
+            }
+            Err(e) => {
+                return (
+                    Status::Failed(format!("Could not wait: {}", e)),
+                    start.elapsed(),
+                )
+            }
+        }
+    };
+    let elapsed = start.elapsed();
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+    match status_of(exit, &stdout, &stderr) {
+        Ok((answer, duration)) => (Status::Solved(answer), duration),
+        Err(status) => (status, elapsed),
+    }
+}
+
+/// Solves the parts of the jobs in child processes, with at most `workers` of them at a time. The
+/// command of a part is given by `command`, which should run [`solve_part`].
+pub fn run_all(
+    jobs: Vec<Job>,
+    workers: usize,
+    limits: Limits,
+    command: impl Fn(&Day, Part, &Params) -> Command + Sync,
+) -> Vec<Run> {
+    let mut outcomes: Vec<[Option<(Status, Duration)>; 2]> = vec![[None, None]; jobs.len()];
+    let mut tasks = VecDeque::new();
+    for (index, job) in jobs.iter().enumerate() {
+        match &job.setup {
+            Ok(_) => tasks.extend(Part::ALL.map(|part| (index, part))),
+            Err(status) => {
+                outcomes[index] = [(); 2].map(|_| Some((status.clone(), Duration::ZERO)))
+            }
+        }
+    }
+
+    let tasks = Mutex::new(tasks);
+    let outcomes = Mutex::new(outcomes);
+    thread::scope(|scope| {
+        for _ in 0..workers.max(1) {
+            scope.spawn(|| loop {
+                let Some((index, part)) = tasks.lock().unwrap().pop_front() else {
+                    break;
+                };
+                let job = &jobs[index];
+                let (input, params) = job.setup.as_ref().expect("Only days with input are run");
+                let params = &params[part.number() as usize - 1];
+                let outcome = run_child(command(job.day, part, params), input, &limits);
+                outcomes.lock().unwrap()[index][part.number() as usize - 1] = Some(outcome);
+            });
+        }
+    });
+
+    jobs.iter()
+        .zip(outcomes.into_inner().unwrap())
+        .flat_map(|(job, outcomes)| {
+            Part::ALL.into_iter().zip(outcomes).map(|(part, outcome)| {
+                let (status, duration) = outcome.expect("Every part should have an outcome");
+                Run {
+                    year: job.day.year,
+                    day: job.day.day,
+                    part,
+                    status,
+                    duration,
+                }
+            })
+        })
+        .collect()
+}
+
+/// Renders a table of the answers and times per year, with a total row per year, followed by
+/// the details of the parts that went wrong.
+pub fn render(runs: &[Run]) -> String {
+    let width = runs
+        .iter()
+        .map(|run| run.status.label().len())
+        .chain(["part 1".len(), "00/00 solved".len()])
+        .max()
+        .unwrap_or_default()
+        + 2;
+    let mut table = String::new();
+    let mut details = String::new();
+    let mut years: Vec<u16> = runs.iter().map(|run| run.year).collect();
+    years.dedup();
+    for (i, year) in years.into_iter().enumerate() {
+        if i > 0 {
+            table.push('\n');
+        }
+        writeln!(
+            table,
+            "{:<8}{:<width$}{:>9}  {:<width$}{:>9}",
+            year, "part 1", "time", "part 2", "time"
+        )
+        .unwrap();
+        let year_runs: Vec<&Run> = runs.iter().filter(|run| run.year == year).collect();
+        for pair in year_runs.chunks(2) {
+            write!(table, "{:<8}", format!("day {}", pair[0].day)).unwrap();
+            for run in pair {
+                write!(
+                    table,
+                    "{:<width$}{:>9}  ",
+                    run.status.label(),
+                    format_nanos(run.duration.as_nanos() as u64)
+                )
+                .unwrap();
+                if let Some(detail) = run.status.detail() {
+                    writeln!(
+                        details,
+                        "{} day {} part {}: {}",
+                        run.year, run.day, run.part, detail
+                    )
+                    .unwrap();
+                }
+            }
+            truncate_end(&mut table);
+        }
+        write!(table, "{:<8}", "total").unwrap();
+        for part in Part::ALL {
+            let part_runs = year_runs.iter().filter(|run| run.part == part);
+            let solved = part_runs
+                .clone()
+                .filter(|run| matches!(run.status, Status::Solved(_)))
+                .count();
+            let duration: Duration = part_runs.clone().map(|run| run.duration).sum();
+            write!(
+                table,
+                "{:<width$}{:>9}  ",
+                format!("{}/{} solved", solved, part_runs.count()),
+                format_nanos(duration.as_nanos() as u64)
+            )
+            .unwrap();
+        }
+        truncate_end(&mut table);
+    }
+    if !details.is_empty() {
+        table.push('\n');
+        table.push_str(&details);
+    }
+    table
+}
+
+/// Ends a row of the table, dropping the padding after its last column.
+fn truncate_end(table: &mut String) {
+    table.truncate(table.trim_end_matches(' ').len());
+    table.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_runner::Solution;
+
+    struct Echo;
+
+    impl Solution for Echo {
+        const YEAR: u16 = 2023;
+        const DAY: u8 = 1;
+
+        type Input = String;
+        type Answer1 = String;
+        type Answer2 = usize;
+
+        fn parse(input: &str) -> Result<String, ParseError> {
+            Ok(input.trim().to_string())
+        }
+
+        fn part1(input: &String, _params: &Params) -> String {
+            input.clone()
+        }
+
+        fn part2(input: &String, _params: &Params) -> usize {
+            input.len()
+        }
+    }
+
+    static ECHO: Day = Day::of::<Echo>();
+
+    const LIMITS: Limits = Limits {
+        timeout: Duration::from_millis(500),
+        memory: None,
+    };
+
+    fn shell(script: &str) -> Command {
+        let mut command = Command::new("sh");
+        command.args(["-c", script]);
+        command
+    }
+
+    #[test]
+    fn reads_answers_and_failures_of_children() {
+        let run = |script| run_child(shell(script), "", &LIMITS);
+        assert_eq!(
+            run("echo 1500; echo 42"),
+            (Status::Solved("42".to_string()), Duration::from_nanos(1500))
+        );
+        assert_eq!(
+            run("echo 'Could not find route' >&2; exit 101").0,
+            Status::Panicked("Could not find route".to_string())
+        );
+        assert_eq!(
+            run("echo 'memory allocation of 64 bytes failed' >&2; kill -ABRT $$").0,
+            Status::OutOfMemory
+        );
+        assert_eq!(
+            run("exit 3").0,
+            Status::Failed("exited with exit status: 3".to_string())
+        );
+        assert_eq!(run("exec sleep 10"), (Status::TimedOut, LIMITS.timeout));
+    }
+
+    #[test]
+    fn limits_address_space() {
+        let limits = Limits {
+            memory: Some(256 << 20),
+            ..LIMITS
+        };
+        assert_eq!(
+            run_child(shell("echo 0; ulimit -v"), "", &limits).0,
+            Status::Solved((256 << 10).to_string())
+        );
+    }
+
+    #[test]
+    fn runs_parts_in_order_of_jobs() {
+        let jobs = vec![
+            Job {
+                day: &ECHO,
+                setup: Ok(("abc\n".to_string(), [Params::default(), Params::default()])),
+            },
+            Job {
+                day: &ECHO,
+                setup: Err(Status::NoInput),
+            },
+        ];
+        // Echoes the input in part 1 and hangs in part 2
+        let runs = run_all(jobs, 2, LIMITS, |_, part, _| match part {
+            Part::One => shell("echo 0; cat"),
+            Part::Two => shell("exec sleep 10"),
+        });
+        let statuses: Vec<&Status> = runs.iter().map(|run| &run.status).collect();
+        assert_eq!(
+            statuses,
+            [
+                &Status::Solved("abc".to_string()),
+                &Status::TimedOut,
+                &Status::NoInput,
+                &Status::NoInput,
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_table_with_totals() {
+        let run = |day, part, status, millis| Run {
+            year: 2023,
+            day,
+            part,
+            status,
+            duration: Duration::from_millis(millis),
+        };
+        let runs = [
+            run(1, Part::One, Status::Solved("142".to_string()), 2),
+            run(1, Part::Two, Status::Solved("281".to_string()), 3),
+            run(2, Part::One, Status::Solved("8".to_string()), 1),
+            run(2, Part::Two, Status::Panicked("oops".to_string()), 0),
+        ];
+        assert_eq!(
+            render(&runs),
+            "2023    part 1             time  part 2             time\n\
+             day 1   142               2.0ms  281               3.0ms\n\
+             day 2   8                 1.0ms  PANIC               0ns\n\
+             total   2/2 solved        3.0ms  1/2 solved        3.0ms\n\
+             \n\
+             2023 day 2 part 2: panicked: oops\n"
+        );
+    }
+}
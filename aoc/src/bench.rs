@@ -0,0 +1,330 @@
+//! Times the parse step and both parts of a day, and compares the timings with a saved baseline.
+
+use std::fmt::{self, Display, Write};
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use aoc_runner::parse::ParseError;
+use aoc_runner::{Day, Params, Part};
+use serde::{Deserialize, Serialize};
+
+/// A timed step of a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            Stage::Parse => "parse",
+            Stage::Part1 => "part 1",
+            Stage::Part2 => "part 2",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// How many times to run each step.
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    /// Runs before the timed ones, to warm up caches and the allocator.
+    pub warmup: usize,
+    pub samples: usize,
+}
+
+/// The timings of a step, in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub stage: Stage,
+    pub samples: usize,
+    pub median: u64,
+    pub min: u64,
+    pub stddev: u64,
+}
+
+/// Returns the median, minimum and standard deviation of some samples.
+fn statistics(samples: &[Duration]) -> (u64, u64, u64) {
+    let mut nanos: Vec<u64> = samples.iter().map(|s| s.as_nanos() as u64).collect();
+    nanos.sort_unstable();
+    let n = nanos.len();
+    let median = if n % 2 == 1 {
+        nanos[n / 2]
+    } else {
+        (nanos[n / 2 - 1] + nanos[n / 2]) / 2
+    };
+    let mean = nanos.iter().map(|&x| x as f64).sum::<f64>() / n as f64;
+    let variance = nanos
+        .iter()
+        .map(|&x| (x as f64 - mean).powi(2))
+        .sum::<f64>()
+        / n as f64;
+    (median, nanos[0], variance.sqrt().round() as u64)
+}
+
+fn measure(options: Options, mut run: impl FnMut()) -> (u64, u64, u64) {
+    for _ in 0..options.warmup {
+        run();
+    }
+    let samples: Vec<Duration> = (0..options.samples.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            run();
+            start.elapsed()
+        })
+        .collect();
+    statistics(&samples)
+}
+
+/// Times the parse step and the given parts of a day on an input, which should be valid.
+pub fn bench_day(
+    day: &Day,
+    input: &str,
+    params: &[Params; 2],
+    parts: &[Part],
+    options: Options,
+) -> Result<Vec<Record>, ParseError> {
+    let record = |stage, (median, min, stddev)| Record {
+        year: day.year,
+        day: day.day,
+        stage,
+        samples: options.samples.max(1),
+        median,
+        min,
+        stddev,
+    };
+    let parsed = day.parse(input)?;
+    let mut records = vec![record(
+        Stage::Parse,
+        measure(options, || {
+            black_box(day.parse(black_box(input)).ok());
+        }),
+    )];
+    for &part in parts {
+        let stage = match part {
+            Part::One => Stage::Part1,
+            Part::Two => Stage::Part2,
+        };
+        let params = &params[part.number() as usize - 1];
+        records.push(record(
+            stage,
+            measure(options, || {
+                black_box(day.solve_part(parsed.as_ref(), part, params));
+            }),
+        ));
+    }
+    Ok(records)
+}
+
+pub fn load(path: &Path) -> Result<Vec<Record>, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+    serde_json::from_str(&content)
+        .map_err(|e| format!("Invalid baseline {}: {}", path.display(), e))
+}
+
+pub fn save(path: &Path, records: &[Record]) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(records).expect("Records should serialize");
+    fs::write(path, content + "\n")
+        .map_err(|e| format!("Could not write {}: {}", path.display(), e))
+}
+
+/// Formats nanoseconds with a unit that keeps a few significant digits.
+pub(crate) fn format_nanos(nanos: u64) -> String {
+    match nanos {
+        0..=999 => format!("{}ns", nanos),
+        1_000..=999_999 => format!("{:.1}µs", nanos as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.1}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", nanos as f64 / 1e9),
+    }
+}
+
+/// A step whose median got slower than its baseline by more than the threshold.
+#[derive(Debug, PartialEq)]
+pub struct Regression<'a> {
+    pub record: &'a Record,
+    pub baseline: &'a Record,
+    /// The relative change of the median, e.g. 0.25 for 25% slower.
+    pub change: f64,
+}
+
+/// Returns the relative change of the median of each record that is also in the baseline.
+fn changes<'a>(
+    records: &'a [Record],
+    baseline: &'a [Record],
+) -> impl Iterator<Item = (&'a Record, Option<(&'a Record, f64)>)> {
+    records.iter().map(move |record| {
+        let before = baseline
+            .iter()
+            .find(|b| (b.year, b.day, b.stage) == (record.year, record.day, record.stage));
+        let change = before.map(|before| {
+            let change = record.median as f64 / before.median.max(1) as f64 - 1.0;
+            (before, change)
+        });
+        (record, change)
+    })
+}
+
+/// Returns the steps that are slower than in the baseline by more than `threshold`, such as
+/// 0.1 for 10%.
+pub fn regressions<'a>(
+    records: &'a [Record],
+    baseline: &'a [Record],
+    threshold: f64,
+) -> Vec<Regression<'a>> {
+    changes(records, baseline)
+        .filter_map(|(record, change)| {
+            let (baseline, change) = change?;
+            (change > threshold).then_some(Regression {
+                record,
+                baseline,
+                change,
+            })
+        })
+        .collect()
+}
+
+/// Renders a table of the timings, with the change from the baseline if there is one.
+pub fn render(records: &[Record], baseline: &[Record], threshold: f64) -> String {
+    let mut table = format!(
+        "{:<16}{:>10}{:>10}{:>10}{:>10}\n",
+        "", "median", "min", "stddev", "change"
+    );
+    for (record, change) in changes(records, baseline) {
+        let label = format!("{} {:>2} {}", record.year, record.day, record.stage);
+        let change = match change {
+            Some((_, change)) => {
+                let flag = if change > threshold { "  slower" } else { "" };
+                format!("{:>+9.1}%{}", change * 100.0, flag)
+            }
+            None => String::new(),
+        };
+        writeln!(
+            table,
+            "{:<16}{:>10}{:>10}{:>10}{}",
+            label,
+            format_nanos(record.median),
+            format_nanos(record.min),
+            format_nanos(record.stddev),
+            change
+        )
+        .unwrap();
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_runner::{parse, Solution};
+
+    fn record(day: u8, stage: Stage, median: u64) -> Record {
+        Record {
+            year: 2023,
+            day,
+            stage,
+            samples: 5,
+            median,
+            min: median / 2,
+            stddev: 10,
+        }
+    }
+
+    #[test]
+    fn computes_statistics() {
+        let samples = [4, 1, 3, 2, 5].map(Duration::from_nanos);
+        assert_eq!(statistics(&samples), (3, 1, 1));
+        let samples = [10, 20].map(Duration::from_nanos);
+        assert_eq!(statistics(&samples), (15, 10, 5));
+    }
+
+    #[test]
+    fn formats_with_units() {
+        assert_eq!(format_nanos(999), "999ns");
+        assert_eq!(format_nanos(1_500), "1.5µs");
+        assert_eq!(format_nanos(12_340_000), "12.3ms");
+        assert_eq!(format_nanos(2_500_000_000), "2.50s");
+    }
+
+    #[test]
+    fn flags_regressions_beyond_threshold() {
+        let baseline = [record(1, Stage::Part1, 1000), record(1, Stage::Part2, 1000)];
+        let records = [
+            record(1, Stage::Part1, 1050),
+            record(1, Stage::Part2, 1200),
+            record(2, Stage::Part1, 5000),
+        ];
+        let regressions = regressions(&records, &baseline, 0.1);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].record.stage, Stage::Part2);
+        assert!((regressions[0].change - 0.2).abs() < 1e-9);
+
+        assert_eq!(
+            render(&records, &baseline, 0.1),
+            "                    median       min    stddev    change\n\
+             2023  1 part 1       1.1µs     525ns      10ns     +5.0%\n\
+             2023  1 part 2       1.2µs     600ns      10ns    +20.0%  slower\n\
+             2023  2 part 1       5.0µs     2.5µs      10ns\n"
+        );
+    }
+
+    #[test]
+    fn saves_and_loads_baseline() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("bench.json");
+        let records = vec![record(1, Stage::Parse, 10)];
+        save(&path, &records).unwrap();
+        assert!(fs::read_to_string(&path)
+            .unwrap()
+            .contains("\"stage\": \"parse\""));
+        assert_eq!(load(&path), Ok(records));
+    }
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u16 = 2022;
+        const DAY: u8 = 3;
+
+        type Input = Vec<u64>;
+        type Answer1 = u64;
+        type Answer2 = u64;
+
+        fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+            input.split(',').map(|n| parse::number(input, n)).collect()
+        }
+
+        fn part1(input: &Vec<u64>, _params: &Params) -> u64 {
+            input.iter().sum()
+        }
+
+        fn part2(input: &Vec<u64>, _params: &Params) -> u64 {
+            input.iter().product()
+        }
+    }
+
+    #[test]
+    fn times_every_stage() {
+        let options = Options {
+            warmup: 1,
+            samples: 3,
+        };
+        let params = [Params::default(), Params::default()];
+        let day = Day::of::<Sum>();
+        let records = bench_day(&day, "1,2,3", &params, &Part::ALL, options).unwrap();
+        let stages: Vec<Stage> = records.iter().map(|r| r.stage).collect();
+        assert_eq!(stages, [Stage::Parse, Stage::Part1, Stage::Part2]);
+        assert!(records.iter().all(|r| r.samples == 3 && r.min <= r.median));
+
+        let records = bench_day(&day, "1,2,3", &params, &[Part::Two], options).unwrap();
+        let stages: Vec<Stage> = records.iter().map(|r| r.stage).collect();
+        assert_eq!(stages, [Stage::Parse, Stage::Part2]);
+    }
+}
@@ -0,0 +1,277 @@
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::config::Config;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies the tool to the Advent of Code servers, as asked by its maintainer.
+const USER_AGENT: &str = concat!("github.com/nootr/aoc v", env!("CARGO_PKG_VERSION"));
+
+/// The minimum time between two requests to the server.
+const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Spaces out requests, keeping the time of the last request in a file so separate runs are
+/// throttled as well.
+pub struct Throttle {
+    path: PathBuf,
+    interval: Duration,
+}
+
+impl Throttle {
+    pub fn new(path: PathBuf, interval: Duration) -> Self {
+        Self { path, interval }
+    }
+
+    fn now() -> Duration {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("The clock should be past the epoch")
+    }
+
+    /// Blocks until the interval since the last request has passed, and records a new request.
+    pub fn wait(&self) -> Result<(), String> {
+        let last = fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(Duration::from_millis);
+        if let Some(last) = last {
+            let next = last + self.interval;
+            let now = Self::now();
+            if next > now {
+                thread::sleep(next - now);
+            }
+        }
+
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|e| format!("Could not create {}: {}", dir.display(), e))?;
+        }
+        // Round up, so the next request is never early
+        let millis = Self::now().as_millis() + 1;
+        fs::write(&self.path, millis.to_string())
+            .map_err(|e| format!("Could not write {}: {}", self.path.display(), e))
+    }
+}
+
+/// An authenticated client for the Advent of Code website.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    throttle: Throttle,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, throttle: Throttle) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            throttle,
+        }
+    }
+
+    pub fn from_config(config: &Config) -> Result<Self, String> {
+        Ok(Self::new(
+            config.base_url(),
+            &config.session()?,
+            Throttle::new(config.last_request_file(), MIN_INTERVAL),
+        ))
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// Returns the body of a page, failing on any status other than 200 OK.
+    pub fn get(&self, path: &str) -> Result<String, String> {
+        self.throttle.wait()?;
+        let response = self
+            .agent
+            .get(&self.url(path))
+            .set("Cookie", &self.cookie())
+            .call();
+        Self::body(path, response)
+    }
+
+    /// Posts a form and returns the body of the response page.
+    pub fn post_form(&self, path: &str, fields: &[(&str, &str)]) -> Result<String, String> {
+        self.throttle.wait()?;
+        let response = self
+            .agent
+            .post(&self.url(path))
+            .set("Cookie", &self.cookie())
+            .send_form(fields);
+        Self::body(path, response)
+    }
+
+    fn body(path: &str, response: Result<ureq::Response, ureq::Error>) -> Result<String, String> {
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|e| format!("Could not read the response to {}: {}", path, e)),
+            // The server answers 400 or 500 to unknown sessions
+            Err(ureq::Error::Status(400 | 500, _)) => Err(format!(
+                "The server refused {}, the session cookie is probably invalid or expired",
+                path
+            )),
+            Err(ureq::Error::Status(404, _)) => Err(format!(
+                "{} was not found, is the puzzle unlocked yet?",
+                path
+            )),
+            Err(ureq::Error::Status(code, _)) => {
+                Err(format!("The server answered {} to {}", code, path))
+            }
+            Err(e) => Err(format!("Could not request {}: {}", path, e)),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use std::time::Instant;
+
+    /// A request received by the [`MockServer`].
+    #[derive(Debug, Clone)]
+    pub struct Request {
+        pub method: String,
+        pub url: String,
+        pub headers: Vec<(String, String)>,
+        pub body: String,
+    }
+
+    impl Request {
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .iter()
+                .find(|(n, _)| n.eq_ignore_ascii_case(name))
+                .map(|(_, v)| v.as_str())
+        }
+    }
+
+    /// A local stand-in for the Advent of Code server, answering every request with the
+    /// response returned by a handler.
+    pub struct MockServer {
+        pub url: String,
+        pub requests: Arc<Mutex<Vec<Request>>>,
+    }
+
+    impl MockServer {
+        pub fn start<F>(handler: F) -> Self
+        where
+            F: Fn(&Request) -> (u16, String) + Send + 'static,
+        {
+            let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+            let requests = Arc::new(Mutex::new(vec![]));
+            let log = requests.clone();
+            thread::spawn(move || {
+                for mut incoming in server.incoming_requests() {
+                    let mut body = String::new();
+                    incoming.as_reader().read_to_string(&mut body).unwrap();
+                    let request = Request {
+                        method: incoming.method().to_string(),
+                        url: incoming.url().to_string(),
+                        headers: incoming
+                            .headers()
+                            .iter()
+                            .map(|h| (h.field.to_string(), h.value.to_string()))
+                            .collect(),
+                        body,
+                    };
+                    let (status, body) = handler(&request);
+                    log.lock().unwrap().push(request);
+                    let response = tiny_http::Response::from_string(body).with_status_code(status);
+                    incoming.respond(response).unwrap();
+                }
+            });
+            Self { url, requests }
+        }
+
+        pub fn requests(&self) -> Vec<Request> {
+            self.requests.lock().unwrap().clone()
+        }
+
+        /// Returns a client for this server that doesn't throttle.
+        pub fn client(&self, dir: &std::path::Path) -> Client {
+            let throttle = Throttle::new(dir.join("last-request"), Duration::ZERO);
+            Client::new(&self.url, "secret", throttle)
+        }
+    }
+
+    #[test]
+    fn sends_session_and_user_agent() {
+        let server = MockServer::start(|_| (200, "hello".to_string()));
+        let dir = tempfile::tempdir().unwrap();
+        let client = server.client(dir.path());
+        assert_eq!(client.get("/2023/day/1/input").unwrap(), "hello");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].url, "/2023/day/1/input");
+        assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+        assert_eq!(requests[0].header("User-Agent"), Some(USER_AGENT));
+    }
+
+    #[test]
+    fn posts_forms() {
+        let server = MockServer::start(|_| (200, "ok".to_string()));
+        let dir = tempfile::tempdir().unwrap();
+        let client = server.client(dir.path());
+        let fields = [("level", "1"), ("answer", "42")];
+        assert_eq!(
+            client.post_form("/2023/day/1/answer", &fields).unwrap(),
+            "ok"
+        );
+
+        let request = &server.requests()[0];
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.body, "level=1&answer=42");
+        assert_eq!(request.header("Cookie"), Some("session=secret"));
+    }
+
+    #[test]
+    fn reports_invalid_session() {
+        let server = MockServer::start(|_| (400, "Puzzle inputs differ by user.".to_string()));
+        let dir = tempfile::tempdir().unwrap();
+        let error = server
+            .client(dir.path())
+            .get("/2023/day/1/input")
+            .unwrap_err();
+        assert!(error.contains("session cookie"), "{}", error);
+    }
+
+    #[test]
+    fn reports_locked_puzzle() {
+        let server = MockServer::start(|_| (404, "Not found".to_string()));
+        let dir = tempfile::tempdir().unwrap();
+        let error = server
+            .client(dir.path())
+            .get("/2023/day/25/input")
+            .unwrap_err();
+        assert!(error.contains("unlocked"), "{}", error);
+    }
+
+    #[test]
+    fn throttles_across_instances() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("last-request");
+        let interval = Duration::from_millis(200);
+        let start = Instant::now();
+        Throttle::new(path.clone(), interval).wait().unwrap();
+        Throttle::new(path, interval).wait().unwrap();
+        assert!(start.elapsed() >= interval);
+    }
+}
@@ -0,0 +1,117 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+/// The settings of the `aoc` binary, read from `config.toml` in the config directory.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// The value of the `session` cookie of adventofcode.com.
+    session: Option<String>,
+    /// The server to talk to, which defaults to adventofcode.com.
+    base_url: Option<String>,
+    /// The id of the private leaderboard to report on.
+    leaderboard: Option<u64>,
+    #[serde(skip)]
+    dir: PathBuf,
+}
+
+impl Config {
+    /// Returns the config directory: `$AOC_CONFIG_DIR`, or `aoc` in the user's config directory.
+    fn dir() -> Result<PathBuf, String> {
+        if let Some(dir) = env::var_os("AOC_CONFIG_DIR") {
+            return Ok(PathBuf::from(dir));
+        }
+        if let Some(dir) = env::var_os("XDG_CONFIG_HOME") {
+            return Ok(PathBuf::from(dir).join("aoc"));
+        }
+        env::var_os("HOME")
+            .map(|home| PathBuf::from(home).join(".config").join("aoc"))
+            .ok_or_else(|| "Could not find the config directory, set AOC_CONFIG_DIR".to_string())
+    }
+
+    pub fn load() -> Result<Self, String> {
+        Self::load_from(Self::dir()?)
+    }
+
+    /// Loads the config from a directory, which doesn't need to have a `config.toml`.
+    pub fn load_from(dir: PathBuf) -> Result<Self, String> {
+        let path = dir.join("config.toml");
+        let mut config: Config = match fs::read_to_string(&path) {
+            Ok(content) => toml::from_str(&content)
+                .map_err(|e| format!("Invalid config in {}: {}", path.display(), e))?,
+            Err(_) => Config::default(),
+        };
+        config.dir = dir;
+        Ok(config)
+    }
+
+    /// Returns the session cookie, preferring `$AOC_SESSION` over the config file.
+    pub fn session(&self) -> Result<String, String> {
+        env::var("AOC_SESSION")
+            .ok()
+            .or_else(|| self.session.clone())
+            .map(|session| session.trim().to_string())
+            .filter(|session| !session.is_empty())
+            .ok_or_else(|| {
+                format!(
+                    "No session cookie found, set AOC_SESSION or `session` in {}",
+                    self.dir.join("config.toml").display()
+                )
+            })
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.base_url.as_deref().unwrap_or(crate::client::BASE_URL)
+    }
+
+    pub fn leaderboard(&self) -> Option<u64> {
+        self.leaderboard
+    }
+
+    /// Returns the file in which a downloaded leaderboard is kept.
+    pub fn leaderboard_cache(&self, year: u16, id: u64) -> PathBuf {
+        self.dir.join(format!("leaderboard-{}-{}.json", year, id))
+    }
+
+    /// Returns the file in which the time of the last request is kept, to throttle requests
+    /// across runs.
+    pub fn last_request_file(&self) -> PathBuf {
+        self.dir.join("last-request")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_config_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config::load_from(dir.path().to_path_buf()).unwrap();
+        assert_eq!(config.base_url(), crate::client::BASE_URL);
+        assert!(config.session.is_none());
+    }
+
+    #[test]
+    fn reads_config_file() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("config.toml"),
+            "session = \"abc\"\nbase_url = \"http://localhost:8080\"\n",
+        )
+        .unwrap();
+        let config = Config::load_from(dir.path().to_path_buf()).unwrap();
+        assert_eq!(config.session.as_deref(), Some("abc"));
+        assert_eq!(config.base_url(), "http://localhost:8080");
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("config.toml"), "sesion = \"abc\"\n").unwrap();
+        assert!(Config::load_from(dir.path().to_path_buf()).is_err());
+    }
+}
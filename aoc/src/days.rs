@@ -0,0 +1,39 @@
+use aoc_runner::Day;
+
+/// All days linked into the runner, in chronological order.
+pub const DAYS: &[Day] = &[
+    Day::of::<aoc_2015_day_1::Day1>(),
+    Day::of::<aoc_2015_day_2::Day2>(),
+    Day::of::<aoc_2015_day_3::Day3>(),
+    Day::of::<aoc_2015_day_4::Day4>(),
+    Day::of::<aoc_2015_day_5::Day5>(),
+    Day::of::<aoc_2015_day_6::Day6>(),
+    Day::of::<aoc_2015_day_7::Day7>(),
+    Day::of::<aoc_2022_day_1::Day1>(),
+    Day::of::<aoc_2023_day_1::Day1>(),
+    Day::of::<aoc_2023_day_2::Day2>(),
+    Day::of::<aoc_2023_day_3::Day3>(),
+    Day::of::<aoc_2023_day_4::Day4>(),
+    Day::of::<aoc_2023_day_5::Day5>(),
+    Day::of::<aoc_2023_day_6::Day6>(),
+    Day::of::<aoc_2023_day_7::Day7>(),
+    Day::of::<aoc_2023_day_8::Day8>(),
+    Day::of::<aoc_2023_day_9::Day9>(),
+    Day::of::<aoc_2023_day_10::Day10>(),
+    Day::of::<aoc_2023_day_11::Day11>(),
+    Day::of::<aoc_2023_day_12::Day12>(),
+    Day::of::<aoc_2023_day_13::Day13>(),
+    Day::of::<aoc_2023_day_14::Day14>(),
+    Day::of::<aoc_2023_day_15::Day15>(),
+    Day::of::<aoc_2023_day_16::Day16>(),
+    Day::of::<aoc_2023_day_17::Day17>(),
+    Day::of::<aoc_2023_day_18::Day18>(),
+    Day::of::<aoc_2023_day_19::Day19>(),
+    Day::of::<aoc_2023_day_20::Day20>(),
+    Day::of::<aoc_2023_day_21::Day21>(),
+];
+
+/// Returns the registered day for the given year and day number.
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
@@ -0,0 +1,68 @@
+use std::fs;
+use std::path::Path;
+
+use crate::client::Client;
+
+/// Whether an input was downloaded or already on disk.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded,
+    Cached,
+}
+
+/// Downloads the input of a day to `path`, unless it's already there.
+pub fn fetch_input(client: &Client, year: u16, day: u8, path: &Path) -> Result<Fetched, String> {
+    if path.exists() {
+        return Ok(Fetched::Cached);
+    }
+
+    let input = client.get(&format!("/{}/day/{}/input", year, day))?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("Could not create {}: {}", dir.display(), e))?;
+    }
+    // Write to a temporary file first, so an interrupted download doesn't look cached
+    let partial = path.with_extension("partial");
+    fs::write(&partial, input)
+        .and_then(|()| fs::rename(&partial, path))
+        .map_err(|e| format!("Could not write {}: {}", path.display(), e))?;
+    Ok(Fetched::Downloaded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::MockServer;
+
+    #[test]
+    fn downloads_input_once() {
+        let server = MockServer::start(|request| {
+            assert_eq!(request.url, "/2023/day/9/input");
+            (200, "0 3 6 9 12 15\n".to_string())
+        });
+        let dir = tempfile::tempdir().unwrap();
+        let client = server.client(dir.path());
+        let path = dir.path().join("2023").join("day_9").join("input");
+
+        assert_eq!(
+            fetch_input(&client, 2023, 9, &path).unwrap(),
+            Fetched::Downloaded
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "0 3 6 9 12 15\n");
+        assert_eq!(
+            fetch_input(&client, 2023, 9, &path).unwrap(),
+            Fetched::Cached
+        );
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn keeps_nothing_on_failure() {
+        let server = MockServer::start(|_| (500, "Internal Server Error".to_string()));
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("input");
+
+        assert!(fetch_input(&server.client(dir.path()), 2023, 9, &path).is_err());
+        assert!(!path.exists());
+    }
+}
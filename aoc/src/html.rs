@@ -0,0 +1,127 @@
+//! Just enough HTML handling for the pages of adventofcode.com, which are simple and regular.
+
+/// Returns the contents of all `tag` elements, in document order. Elements of the same tag are
+/// not expected to nest.
+pub fn elements<'a>(html: &'a str, tag: &str) -> Vec<&'a str> {
+    let open = format!("<{}", tag);
+    let close = format!("</{}>", tag);
+    let mut contents = vec![];
+    let mut rest = html;
+    while let Some(start) = rest.find(&open) {
+        let after = &rest[start + open.len()..];
+        // Skip longer tags with the same prefix, such as <em> and <emph>
+        if !after.starts_with(['>', ' ']) {
+            rest = after;
+            continue;
+        }
+        let Some(content_start) = after.find('>') else {
+            break;
+        };
+        let content = &after[content_start + 1..];
+        let Some(end) = content.find(&close) else {
+            break;
+        };
+        contents.push(&content[..end]);
+        rest = &content[end + close.len()..];
+    }
+    contents
+}
+
+/// Removes all tags, keeping their text.
+pub fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// Replaces character references, such as `&lt;` and `&#39;`, by the characters they stand for.
+pub fn decode_entities(text: &str) -> String {
+    let mut decoded = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let Some(end) = rest.find(';').filter(|end| *end <= 10) else {
+            decoded.push('&');
+            rest = &rest[1..];
+            continue;
+        };
+        let c = match &rest[1..end] {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some('\u{a0}'),
+            entity => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(|dec| dec.parse()))
+                .and_then(|code| code.ok())
+                .and_then(char::from_u32),
+        };
+        match c {
+            Some(c) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Returns the text of some HTML, as it would be displayed in a `<pre>` block.
+pub fn text(html: &str) -> String {
+    decode_entities(&strip_tags(html))
+}
+
+/// Returns the text of some HTML with whitespace collapsed, as it would be displayed in a
+/// paragraph.
+pub fn inline_text(html: &str) -> String {
+    text(html).split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_elements() {
+        let html = "<p>a <em>b</em> <emph>x</emph> <em class=\"star\">c</em></p>";
+        assert_eq!(elements(html, "em"), ["b", "c"]);
+        assert_eq!(
+            elements(html, "p"),
+            ["a <em>b</em> <emph>x</emph> <em class=\"star\">c</em>"]
+        );
+    }
+
+    #[test]
+    fn extracts_text() {
+        assert_eq!(
+            text("<code>a &lt;<em>b</em>&gt; &amp;&#39;&#x41;</code>"),
+            "a <b> &'A"
+        );
+        assert_eq!(
+            inline_text("<p>one\n  two <a href=\"/\">three</a></p>"),
+            "one two three"
+        );
+    }
+
+    #[test]
+    fn keeps_unknown_entities() {
+        assert_eq!(decode_entities("a & b &bogus; c"), "a & b &bogus; c");
+    }
+}
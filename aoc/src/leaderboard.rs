@@ -0,0 +1,393 @@
+//! Reads the JSON export of a private leaderboard, and reports the progress of its members per
+//! day.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+use aoc_runner::Part;
+use serde::Deserialize;
+
+use crate::client::Client;
+
+/// How long a downloaded leaderboard is used before it's requested again. The maintainer of
+/// Advent of Code asks not to request a leaderboard more often than this.
+pub const CACHE_TIME: Duration = Duration::from_secs(15 * 60);
+
+/// A timestamp in the export, which older events give as a string.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Timestamp {
+    Number(u64),
+    Text(String),
+}
+
+#[derive(Debug, Deserialize)]
+struct StarJson {
+    get_star_ts: Timestamp,
+}
+
+#[derive(Debug, Deserialize)]
+struct MemberJson {
+    id: u64,
+    name: Option<String>,
+    #[serde(default)]
+    completion_day_level: BTreeMap<String, BTreeMap<String, StarJson>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Export {
+    event: String,
+    members: BTreeMap<String, MemberJson>,
+}
+
+/// A member of a leaderboard, and when they got their stars.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    pub name: String,
+    /// The time each part was solved in seconds since the epoch, per day.
+    pub stars: BTreeMap<u8, [Option<u64>; 2]>,
+}
+
+impl Member {
+    pub fn star(&self, day: u8, part: Part) -> Option<u64> {
+        self.stars.get(&day)?[part.number() as usize - 1]
+    }
+
+    /// Returns how long part 2 took after part 1, in seconds.
+    pub fn part2_delta(&self, day: u8) -> Option<u64> {
+        Some(self.star(day, Part::Two)? - self.star(day, Part::One)?)
+    }
+}
+
+/// A private leaderboard of an event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub year: u16,
+    /// The members, sorted by id.
+    pub members: Vec<Member>,
+}
+
+/// Returns the days since the epoch of a date, after Howard Hinnant's `days_from_civil`.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// Returns when a puzzle unlocked, at midnight EST, in seconds since the epoch.
+pub fn unlock_time(year: u16, day: u8) -> u64 {
+    let days = days_from_civil(year as i64, 12, day as i64);
+    (days * 86400 + 5 * 3600) as u64
+}
+
+/// Formats a number of seconds as hours, minutes and seconds, such as `1:02:03`.
+fn format_seconds(seconds: u64) -> String {
+    format!(
+        "{}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+impl Leaderboard {
+    /// Parses the JSON export of a leaderboard, as served at
+    /// `/YEAR/leaderboard/private/view/ID.json`.
+    pub fn parse(json: &str) -> Result<Self, String> {
+        let export: Export =
+            serde_json::from_str(json).map_err(|e| format!("Invalid leaderboard: {}", e))?;
+        let year = export
+            .event
+            .parse()
+            .map_err(|_| format!("Invalid leaderboard event {:?}", export.event))?;
+        let mut members = vec![];
+        for member in export.members.into_values() {
+            let mut stars = BTreeMap::new();
+            for (day, parts) in member.completion_day_level {
+                let day: u8 = day
+                    .parse()
+                    .ok()
+                    .filter(|day| (1..=25).contains(day))
+                    .ok_or_else(|| format!("Invalid leaderboard day {:?}", day))?;
+                let mut times = [None, None];
+                for (part, star) in parts {
+                    let part = part
+                        .parse()
+                        .ok()
+                        .and_then(Part::from_number)
+                        .ok_or_else(|| format!("Invalid leaderboard part {:?}", part))?;
+                    let time = match star.get_star_ts {
+                        Timestamp::Number(time) => time,
+                        Timestamp::Text(time) => time
+                            .parse()
+                            .map_err(|_| format!("Invalid leaderboard timestamp {:?}", time))?,
+                    };
+                    times[part.number() as usize - 1] = Some(time);
+                }
+                stars.insert(day, times);
+            }
+            members.push(Member {
+                id: member.id,
+                name: member
+                    .name
+                    .unwrap_or_else(|| format!("(anonymous user #{})", member.id)),
+                stars,
+            });
+        }
+        members.sort_by_key(|member| member.id);
+        Ok(Self { year, members })
+    }
+
+    /// Returns the local score each member got for a star: as many points as there are members
+    /// for the first to get it, one less for the second, and so on.
+    pub fn points(&self, day: u8, part: Part) -> Vec<u64> {
+        let mut solvers: Vec<(u64, usize)> = self
+            .members
+            .iter()
+            .enumerate()
+            .filter_map(|(i, member)| Some((member.star(day, part)?, i)))
+            .collect();
+        solvers.sort();
+        let mut points = vec![0; self.members.len()];
+        for (rank, (_, i)) in solvers.into_iter().enumerate() {
+            points[i] = (self.members.len() - rank) as u64;
+        }
+        points
+    }
+
+    /// Returns the local score of each member over the days up to and including `day`.
+    pub fn scores_after(&self, day: u8) -> Vec<u64> {
+        let mut scores = vec![0; self.members.len()];
+        for day in 1..=day {
+            for part in Part::ALL {
+                for (score, points) in scores.iter_mut().zip(self.points(day, part)) {
+                    *score += points;
+                }
+            }
+        }
+        scores
+    }
+
+    /// Returns the rank of each member by local score after `day`, where members with the same
+    /// score share a rank.
+    pub fn ranks_after(&self, day: u8) -> Vec<usize> {
+        let scores = self.scores_after(day);
+        scores
+            .iter()
+            .map(|score| scores.iter().filter(|other| *other > score).count() + 1)
+            .collect()
+    }
+
+    /// Returns the days on which any member got a star.
+    pub fn days(&self) -> Vec<u8> {
+        let mut days: Vec<u8> = self
+            .members
+            .iter()
+            .flat_map(|member| member.stars.keys().copied())
+            .collect();
+        days.sort();
+        days.dedup();
+        days
+    }
+
+    /// Renders a table of the times since the puzzle unlocked at which each member solved the
+    /// parts of a day, with the points they got and their rank after the day. Members are
+    /// sorted by rank. The times from our own ledger are added below, when known.
+    pub fn render_day(&self, day: u8, ledger: [Option<u64>; 2]) -> String {
+        let unlock = unlock_time(self.year, day);
+        let since_unlock = |time: Option<u64>| {
+            time.map_or("-".to_string(), |time| {
+                format_seconds(time.saturating_sub(unlock))
+            })
+        };
+        let points: Vec<u64> = Part::ALL
+            .iter()
+            .map(|part| self.points(day, *part))
+            .fold(vec![0; self.members.len()], |total, points| {
+                total.iter().zip(points).map(|(a, b)| a + b).collect()
+            });
+        let ranks = self.ranks_after(day);
+        let previous_ranks = self.ranks_after(day - 1);
+
+        let width = self
+            .members
+            .iter()
+            .map(|member| member.name.len())
+            .chain(["ledger".len(), "member".len()])
+            .max()
+            .unwrap_or_default()
+            + 2;
+        let mut table = format!(
+            "{} day {}\n{:<width$}{:>10}{:>10}{:>10}{:>8}  rank\n",
+            self.year, day, "member", "part 1", "part 2", "delta", "points"
+        );
+        let mut order: Vec<usize> = (0..self.members.len()).collect();
+        order.sort_by_key(|i| (ranks[*i], &self.members[*i].name));
+        for i in order {
+            let member = &self.members[i];
+            let change = previous_ranks[i] as i64 - ranks[i] as i64;
+            let change = match change {
+                0 => String::new(),
+                change => format!(" ({:+})", change),
+            };
+            writeln!(
+                table,
+                "{:<width$}{:>10}{:>10}{:>10}{:>8}  {}{}",
+                member.name,
+                since_unlock(member.star(day, Part::One)),
+                since_unlock(member.star(day, Part::Two)),
+                member
+                    .part2_delta(day)
+                    .map_or("-".to_string(), format_seconds),
+                points[i],
+                ranks[i],
+                change
+            )
+            .unwrap();
+        }
+        if ledger.iter().any(Option::is_some) {
+            let delta = match ledger {
+                [Some(one), Some(two)] => format_seconds(two.saturating_sub(one)),
+                _ => "-".to_string(),
+            };
+            writeln!(
+                table,
+                "{:<width$}{:>10}{:>10}{:>10}",
+                "ledger",
+                since_unlock(ledger[0]),
+                since_unlock(ledger[1]),
+                delta
+            )
+            .unwrap();
+        }
+        table
+    }
+}
+
+/// Returns the export of a leaderboard, from `cache` when it was downloaded recently, or else
+/// from the server, keeping it in `cache` for next time.
+pub fn load_cached(client: &Client, year: u16, id: u64, cache: &Path) -> Result<String, String> {
+    let age = fs::metadata(cache)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok());
+    if age.is_some_and(|age| age < CACHE_TIME) {
+        if let Ok(json) = fs::read_to_string(cache) {
+            return Ok(json);
+        }
+    }
+    let json = client.get(&format!("/{}/leaderboard/private/view/{}.json", year, id))?;
+    if let Some(dir) = cache.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("Could not create {}: {}", dir.display(), e))?;
+    }
+    fs::write(cache, &json).map_err(|e| format!("Could not write {}: {}", cache.display(), e))?;
+    Ok(json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::MockServer;
+
+    /// Day 1 of 2023 unlocked at 1701406800.
+    const EXPORT: &str = r#"{
+        "event": "2023",
+        "owner_id": 1,
+        "members": {
+            "1": {
+                "id": 1, "name": "alice", "stars": 3, "local_score": 0, "global_score": 0,
+                "last_star_ts": 0,
+                "completion_day_level": {
+                    "1": {"1": {"get_star_ts": 1701407100, "star_index": 0},
+                          "2": {"get_star_ts": 1701407400, "star_index": 0}},
+                    "2": {"1": {"get_star_ts": 1701497000, "star_index": 0}}
+                }
+            },
+            "2": {
+                "id": 2, "name": "bob", "stars": 4, "local_score": 0, "global_score": 0,
+                "last_star_ts": 0,
+                "completion_day_level": {
+                    "1": {"1": {"get_star_ts": "1701407000", "star_index": 0}},
+                    "2": {"1": {"get_star_ts": 1701493300, "star_index": 0},
+                          "2": {"get_star_ts": 1701494000, "star_index": 0}}
+                }
+            },
+            "3": {
+                "id": 3, "name": null, "stars": 0, "local_score": 0, "global_score": 0,
+                "last_star_ts": 0, "completion_day_level": {}
+            }
+        }
+    }"#;
+
+    #[test]
+    fn reads_star_times() {
+        let leaderboard = Leaderboard::parse(EXPORT).unwrap();
+        assert_eq!(leaderboard.year, 2023);
+        assert_eq!(unlock_time(2023, 1), 1701406800);
+        let [alice, bob, anonymous] = &leaderboard.members[..] else {
+            panic!("Expected three members");
+        };
+        assert_eq!(alice.star(1, Part::Two), Some(1701407400));
+        assert_eq!(alice.part2_delta(1), Some(300));
+        assert_eq!(bob.star(1, Part::One), Some(1701407000));
+        assert_eq!(bob.part2_delta(1), None);
+        assert_eq!(anonymous.name, "(anonymous user #3)");
+        assert_eq!(leaderboard.days(), [1, 2]);
+    }
+
+    #[test]
+    fn rejects_days_outside_the_calendar() {
+        for day in ["0", "26", "x"] {
+            let export = EXPORT.replacen(
+                r#""2": {"1": {"get_star_ts": 1701497000"#,
+                &format!(r#""{}": {{"1": {{"get_star_ts": 1701497000"#, day),
+                1,
+            );
+            assert_eq!(
+                Leaderboard::parse(&export),
+                Err(format!("Invalid leaderboard day {:?}", day))
+            );
+        }
+    }
+
+    #[test]
+    fn scores_and_ranks_members() {
+        let leaderboard = Leaderboard::parse(EXPORT).unwrap();
+        assert_eq!(leaderboard.points(1, Part::One), [2, 3, 0]);
+        assert_eq!(leaderboard.scores_after(1), [5, 3, 0]);
+        assert_eq!(leaderboard.ranks_after(1), [1, 2, 3]);
+        assert_eq!(leaderboard.scores_after(2), [7, 9, 0]);
+        assert_eq!(leaderboard.ranks_after(2), [2, 1, 3]);
+        assert_eq!(
+            leaderboard.render_day(2, [Some(1701493500), None]),
+            "2023 day 2\n\
+             member                   part 1    part 2     delta  points  rank\n\
+             bob                     0:01:40   0:13:20   0:11:40       6  1 (+1)\n\
+             alice                   1:03:20         -         -       2  2 (-1)\n\
+             (anonymous user #3)           -         -         -       0  3\n\
+             ledger                  0:05:00         -         -\n"
+        );
+    }
+
+    #[test]
+    fn caches_downloaded_leaderboards() {
+        let server = MockServer::start(|request| {
+            assert_eq!(request.url, "/2023/leaderboard/private/view/1.json");
+            (200, EXPORT.to_string())
+        });
+        let dir = tempfile::tempdir().unwrap();
+        let client = server.client(dir.path());
+        let cache = dir.path().join("leaderboard-2023-1.json");
+        assert_eq!(load_cached(&client, 2023, 1, &cache).unwrap(), EXPORT);
+        assert_eq!(load_cached(&client, 2023, 1, &cache).unwrap(), EXPORT);
+        assert_eq!(server.requests().len(), 1);
+    }
+}
@@ -0,0 +1,311 @@
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aoc_runner::Part;
+use serde::{Deserialize, Serialize};
+
+/// What the server said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The answer was submitted too soon after the previous one, so it wasn't checked.
+    TooRecent,
+    /// The part was already solved, so the answer wasn't checked.
+    AlreadySolved,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+            Verdict::TooRecent => "too recent",
+            Verdict::AlreadySolved => "already solved",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// A single submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    /// Seconds since the epoch at which the answer was submitted.
+    pub time: u64,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// The number of seconds the server asked to wait before the next attempt.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait: Option<u64>,
+}
+
+impl Attempt {
+    pub fn new(part: Part, answer: &str, verdict: Verdict, wait: Option<Duration>) -> Self {
+        Self {
+            time: now().as_secs(),
+            part: part.number(),
+            answer: answer.to_string(),
+            verdict,
+            wait: wait.map(|wait| wait.as_secs()),
+        }
+    }
+}
+
+/// Why an answer should not be submitted.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadyCorrect,
+    KnownCorrect(String),
+    KnownWrong(Verdict),
+    AboveBound(i128),
+    BelowBound(i128),
+    Cooldown(Duration),
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::AlreadyCorrect => write!(f, "This answer was already accepted"),
+            Refusal::KnownCorrect(answer) => {
+                write!(f, "The part was already solved with {}", answer)
+            }
+            Refusal::KnownWrong(verdict) => {
+                write!(f, "This answer was submitted before and was {}", verdict)
+            }
+            Refusal::AboveBound(bound) => {
+                write!(
+                    f,
+                    "The answer should be lower than {}, which was too high",
+                    bound
+                )
+            }
+            Refusal::BelowBound(bound) => {
+                write!(
+                    f,
+                    "The answer should be higher than {}, which was too low",
+                    bound
+                )
+            }
+            Refusal::Cooldown(left) => {
+                write!(f, "The server asked to wait another {}s", left.as_secs())
+            }
+        }
+    }
+}
+
+fn now() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("The clock should be past the epoch")
+}
+
+/// The answers submitted for a day, kept as one JSON object per line.
+pub struct Ledger {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl Ledger {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let attempts = match fs::read_to_string(path) {
+            Ok(content) => content
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| !line.trim().is_empty())
+                .map(|(i, line)| {
+                    serde_json::from_str(line).map_err(|e| {
+                        format!("Invalid entry in {}:{}: {}", path.display(), i + 1, e)
+                    })
+                })
+                .collect::<Result<_, _>>()?,
+            Err(_) => vec![],
+        };
+        Ok(Self {
+            path: path.to_path_buf(),
+            attempts,
+        })
+    }
+
+    pub fn attempts(&self, part: Part) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |attempt| attempt.part == part.number())
+    }
+
+    /// Returns the accepted answer of a part, if any.
+    pub fn correct_answer(&self, part: Part) -> Option<&str> {
+        self.attempts(part)
+            .find(|attempt| attempt.verdict == Verdict::Correct)
+            .map(|attempt| attempt.answer.as_str())
+    }
+
+    /// Returns when the accepted answer of a part was submitted, in seconds since the epoch.
+    pub fn solved_at(&self, part: Part) -> Option<u64> {
+        self.attempts(part)
+            .find(|attempt| attempt.verdict == Verdict::Correct)
+            .map(|attempt| attempt.time)
+    }
+
+    /// Returns the exclusive bounds the answer of a part is known to lie between.
+    pub fn bounds(&self, part: Part) -> (Option<i128>, Option<i128>) {
+        let numeric = |verdict| {
+            self.attempts(part)
+                .filter(move |attempt| attempt.verdict == verdict)
+                .filter_map(|attempt| attempt.answer.parse::<i128>().ok())
+        };
+        (
+            numeric(Verdict::TooLow).max(),
+            numeric(Verdict::TooHigh).min(),
+        )
+    }
+
+    /// Checks whether an answer is worth submitting, given the earlier attempts.
+    pub fn check(&self, part: Part, answer: &str) -> Result<(), Refusal> {
+        if let Some(correct) = self.correct_answer(part) {
+            return Err(if correct == answer {
+                Refusal::AlreadyCorrect
+            } else {
+                Refusal::KnownCorrect(correct.to_string())
+            });
+        }
+
+        if let Some(attempt) = self.attempts(part).find(|attempt| {
+            attempt.answer == answer
+                && matches!(
+                    attempt.verdict,
+                    Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow
+                )
+        }) {
+            return Err(Refusal::KnownWrong(attempt.verdict));
+        }
+
+        if let Ok(value) = answer.parse::<i128>() {
+            let (lower, upper) = self.bounds(part);
+            if let Some(upper) = upper.filter(|upper| value >= *upper) {
+                return Err(Refusal::AboveBound(upper));
+            }
+            if let Some(lower) = lower.filter(|lower| value <= *lower) {
+                return Err(Refusal::BelowBound(lower));
+            }
+        }
+
+        // The cooldown applies to both parts of all days, but is only known for this one
+        if let Some(until) = self
+            .attempts
+            .iter()
+            .filter_map(|attempt| attempt.wait.map(|wait| attempt.time + wait))
+            .max()
+        {
+            let now = now().as_secs();
+            if until > now {
+                return Err(Refusal::Cooldown(Duration::from_secs(until - now)));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Appends an attempt to the ledger file.
+    pub fn record(&mut self, attempt: Attempt) -> Result<(), String> {
+        let line = serde_json::to_string(&attempt).expect("Attempts should serialize");
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| writeln!(file, "{}", line))
+            .map_err(|e| format!("Could not write {}: {}", self.path.display(), e))?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ledger(attempts: &[(Part, &str, Verdict)]) -> (tempfile::TempDir, Ledger) {
+        let dir = tempfile::tempdir().unwrap();
+        let mut ledger = Ledger::load(&dir.path().join("ledger.jsonl")).unwrap();
+        for (part, answer, verdict) in attempts {
+            let mut attempt = Attempt::new(*part, answer, *verdict, None);
+            attempt.time = 0;
+            ledger.record(attempt).unwrap();
+        }
+        (dir, ledger)
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let (_dir, ledger) = ledger(&[(Part::One, "abc", Verdict::Wrong)]);
+        assert_eq!(
+            ledger.check(Part::One, "abc"),
+            Err(Refusal::KnownWrong(Verdict::Wrong))
+        );
+        assert_eq!(ledger.check(Part::One, "abd"), Ok(()));
+        assert_eq!(ledger.check(Part::Two, "abc"), Ok(()));
+    }
+
+    #[test]
+    fn refuses_answers_outside_bounds() {
+        let (_dir, ledger) = ledger(&[
+            (Part::One, "100", Verdict::TooHigh),
+            (Part::One, "150", Verdict::TooHigh),
+            (Part::One, "20", Verdict::TooLow),
+        ]);
+        assert_eq!(ledger.bounds(Part::One), (Some(20), Some(100)));
+        assert_eq!(
+            ledger.check(Part::One, "120"),
+            Err(Refusal::AboveBound(100))
+        );
+        assert_eq!(
+            ledger.check(Part::One, "101"),
+            Err(Refusal::AboveBound(100))
+        );
+        assert_eq!(ledger.check(Part::One, "-5"), Err(Refusal::BelowBound(20)));
+        assert_eq!(ledger.check(Part::One, "21"), Ok(()));
+        assert_eq!(ledger.check(Part::One, "word"), Ok(()));
+    }
+
+    #[test]
+    fn refuses_solved_parts() {
+        let (_dir, ledger) = ledger(&[
+            (Part::Two, "7", Verdict::TooLow),
+            (Part::Two, "42", Verdict::Correct),
+        ]);
+        assert_eq!(ledger.check(Part::Two, "42"), Err(Refusal::AlreadyCorrect));
+        assert_eq!(
+            ledger.check(Part::Two, "43"),
+            Err(Refusal::KnownCorrect("42".to_string()))
+        );
+        assert_eq!(ledger.correct_answer(Part::One), None);
+    }
+
+    #[test]
+    fn refuses_during_cooldown() {
+        let (_dir, mut ledger) = ledger(&[]);
+        let wait = Duration::from_secs(60);
+        ledger
+            .record(Attempt::new(Part::One, "1", Verdict::Wrong, Some(wait)))
+            .unwrap();
+        assert!(matches!(
+            ledger.check(Part::One, "2"),
+            Err(Refusal::Cooldown(left)) if left <= wait
+        ));
+    }
+
+    #[test]
+    fn survives_reloading() {
+        let (dir, _) = ledger(&[(Part::One, "12", Verdict::TooLow)]);
+        let ledger = Ledger::load(&dir.path().join("ledger.jsonl")).unwrap();
+        assert_eq!(ledger.bounds(Part::One), (Some(12), None));
+    }
+}
@@ -0,0 +1,864 @@
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::process::{self, ExitCode};
+use std::thread;
+use std::time::{Duration, Instant};
+use std::{env, fs};
+
+use aoc_runner::input::Source;
+use aoc_runner::manifest::{self, Manifest};
+use aoc_runner::{day_dir, root, Day, Params, Part};
+use clap::{Parser, Subcommand};
+
+use crate::answers::Answers;
+use crate::batch::{Job, Limits, Run, Status};
+use crate::client::Client;
+use crate::config::Config;
+use crate::fetch::Fetched;
+use crate::leaderboard::Leaderboard;
+use crate::ledger::{Ledger, Verdict};
+use crate::output::Format;
+use crate::puzzle::Puzzle;
+use crate::verify::Outcome;
+
+mod accounts;
+mod answers;
+mod batch;
+mod bench;
+mod client;
+mod config;
+mod days;
+mod fetch;
+mod html;
+mod leaderboard;
+mod ledger;
+mod output;
+mod puzzle;
+mod scaffold;
+mod stars;
+mod submit;
+mod verify;
+mod watch;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code solutions in this repository")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day, or every registered day with --all
+    Run {
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        year: Option<u16>,
+        #[arg(required_unless_present = "all")]
+        day: Option<u8>,
+        /// Solve all registered days in parallel, and summarize their answers and times
+        #[arg(long)]
+        all: bool,
+        /// With --all, only solve the days of this year
+        #[arg(long = "year", value_name = "YEAR", requires = "all")]
+        only_year: Option<u16>,
+        /// With --all, how many days to solve at once [default: the number of CPUs]
+        #[arg(long, value_name = "N", requires = "all")]
+        jobs: Option<usize>,
+        /// Kill a part that takes longer than this many seconds
+        #[arg(long, value_name = "SECS", default_value_t = 60)]
+        timeout: u64,
+        /// Limit the address space of each part to this many MiB, or 0 for no limit
+        #[arg(long, value_name = "MIB", default_value_t = 4096)]
+        memory: u64,
+        /// Read the input from a file instead of the day's own, or from stdin with -
+        #[arg(long, value_name = "PATH", conflicts_with_all = ["all", "example"])]
+        input: Option<String>,
+        /// Read the input from the day's input-example-N
+        #[arg(long, value_name = "N")]
+        example: Option<u32>,
+        /// Override a parameter of the parts that accept it, e.g. --param steps=6
+        #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+        params: Vec<(String, String)>,
+        /// How to print the answers and times
+        #[arg(long, value_enum, default_value_t = Format::Human)]
+        format: Format,
+    },
+    /// Download the puzzle input of a day, unless it was downloaded before
+    Fetch { year: u16, day: u8 },
+    /// Solve a part and submit the answer, unless earlier attempts show it's wrong
+    Submit {
+        year: u16,
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+    },
+    /// Create the crate of a new day from the day_X template of its year
+    New {
+        year: u16,
+        day: u8,
+        /// A saved puzzle page to take the examples and their answers from
+        #[arg(long)]
+        page: Option<PathBuf>,
+    },
+    /// Check every day with an input against the answers in its answers.toml
+    Verify {
+        /// Only check the days of this year
+        year: Option<u16>,
+        /// Fail a part that takes longer than this many seconds
+        #[arg(long, value_name = "SECS", default_value_t = 60)]
+        timeout: u64,
+        /// Limit the address space of each part to this many MiB, or 0 for no limit
+        #[arg(long, value_name = "MIB", default_value_t = 4096)]
+        memory: u64,
+    },
+    /// Time the parse step and both parts of every day with an input, or of a single day
+    Bench {
+        year: Option<u16>,
+        day: Option<u8>,
+        /// Untimed runs of each step before the timed ones
+        #[arg(long, default_value_t = 1)]
+        warmup: usize,
+        /// Timed runs of each step
+        #[arg(long, default_value_t = 10)]
+        samples: usize,
+        /// Save the timings as JSON, to compare later runs with
+        #[arg(long, value_name = "FILE")]
+        save: Option<PathBuf>,
+        /// Compare with timings saved before, and fail if a step got slower
+        #[arg(long, value_name = "FILE")]
+        baseline: Option<PathBuf>,
+        /// How much slower than the baseline a step may get, in percent
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+        /// Leave out a part that takes longer than this many seconds to solve once
+        #[arg(long, value_name = "SECS", default_value_t = 60)]
+        timeout: u64,
+        /// Leave out a part that needs more address space than this many MiB, or 0 for no limit
+        #[arg(long, value_name = "MIB", default_value_t = 4096)]
+        memory: u64,
+    },
+    /// Update the star calendar in the README from the recorded answers
+    Stars {
+        /// Only count the parts whose recorded answer the solution still gives
+        #[arg(long)]
+        verify: bool,
+        /// With --verify, fail a part that takes longer than this many seconds
+        #[arg(long, value_name = "SECS", default_value_t = 60, requires = "verify")]
+        timeout: u64,
+        /// With --verify, limit the address space of each part to this many MiB, or 0 for no
+        /// limit
+        #[arg(long, value_name = "MIB", default_value_t = 4096, requires = "verify")]
+        memory: u64,
+    },
+    /// Write the examples of a saved puzzle page and their answers to the day's manifest
+    Examples { year: u16, day: u8, page: PathBuf },
+    /// Solve a day against the input of every account in its inputs/<account>/ directories, and
+    /// compare with the answers.toml of each account
+    Accounts {
+        year: u16,
+        day: u8,
+        /// How many parts to solve at once [default: the number of CPUs]
+        #[arg(long, value_name = "N")]
+        jobs: Option<usize>,
+        /// Kill a part that takes longer than this many seconds
+        #[arg(long, value_name = "SECS", default_value_t = 60)]
+        timeout: u64,
+        /// Limit the address space of each part to this many MiB, or 0 for no limit
+        #[arg(long, value_name = "MIB", default_value_t = 4096)]
+        memory: u64,
+    },
+    /// Show the progress of the members of a private leaderboard per day, next to our own
+    Leaderboard {
+        year: u16,
+        /// Only show this day
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Read the leaderboard from a JSON export instead of downloading it
+        #[arg(long, value_name = "PATH")]
+        file: Option<PathBuf>,
+        /// The id of the leaderboard, instead of `leaderboard` in the config
+        #[arg(long, conflicts_with = "file")]
+        id: Option<u64>,
+    },
+    /// Re-run the tests and the solution of a day whenever its sources or inputs change
+    Watch {
+        year: u16,
+        day: u8,
+        /// Kill a part that takes longer than this many seconds
+        #[arg(long, value_name = "SECS", default_value_t = 60)]
+        timeout: u64,
+        /// Limit the address space of each part to this many MiB, or 0 for no limit
+        #[arg(long, value_name = "MIB", default_value_t = 4096)]
+        memory: u64,
+    },
+    /// Solve a part against the input on stdin, as a child process of run
+    #[command(hide = true)]
+    SolvePart {
+        year: u16,
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+        params: Vec<(String, String)>,
+    },
+}
+
+fn parse_param(s: &str) -> Result<(String, String), String> {
+    let (name, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("Expected NAME=VALUE, got {:?}", s))?;
+    Ok((name.to_string(), value.to_string()))
+}
+
+/// Reads and normalizes the puzzle input of a day, suggesting to fetch it when it's missing.
+fn read_input(day: &Day, source: &Source) -> Result<String, String> {
+    let dir = day_dir(day.year, day.day);
+    day.load(source, &dir).map_err(|e| {
+        if source.path(&dir) == Some(dir.join("input")) {
+            format!("{} (try `aoc fetch {} {}`)", e, day.year, day.day)
+        } else {
+            e
+        }
+    })
+}
+
+/// Returns the own input of a day, ignoring `AOC_INPUT`.
+fn own_input(day: &Day) -> Source {
+    Source::File(day_dir(day.year, day.day).join("input"))
+}
+
+/// Returns the parameters of a part as set in the manifest of its day, with `overrides` for
+/// those it accepts.
+fn params(day: &Day, source: &Source, part: Part, overrides: &Params) -> Result<Params, String> {
+    let dir = day_dir(day.year, day.day);
+    let mut params = Manifest::load(&dir)?
+        .source_params(day, source, part)
+        .map_err(|e| format!("{}: {}", dir.join(manifest::FILE_NAME).display(), e))?;
+    params.override_with(overrides);
+    Ok(params)
+}
+
+/// Solves a day against an input and prints the answers, timing each part. Each part is solved
+/// in a child process within `limits`, and the time of part 1 includes parsing the input.
+fn run_day(
+    day: &'static Day,
+    source: &Source,
+    overrides: &Params,
+    limits: Limits,
+    format: Format,
+) -> Result<(), String> {
+    let content = read_input(day, source)?;
+    let params = [
+        params(day, source, Part::One, overrides)?,
+        params(day, source, Part::Two, overrides)?,
+    ];
+    let job = Job {
+        day,
+        setup: Ok((content, params)),
+    };
+    // One part at a time, so that they don't slow each other down
+    let runs = run_in_children(vec![job], 1, limits)?;
+    match format {
+        Format::Human => {
+            for run in &runs {
+                if let Status::Solved(answer) = &run.status {
+                    println!("Solution part {}: {}", run.part, answer);
+                }
+            }
+        }
+        Format::Json => print!("{}", output::json(&runs)),
+        Format::Tsv => print!("{}", output::tsv(&runs)),
+    }
+    let failures: Vec<String> = runs
+        .iter()
+        .filter_map(|run| {
+            let detail = run.status.detail()?;
+            Some(format!("Part {} {}", run.part, detail))
+        })
+        .collect();
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(failures.join("\n"))
+    }
+}
+
+fn run(
+    year: u16,
+    day: u8,
+    source: &Source,
+    params: &Params,
+    limits: Limits,
+    format: Format,
+) -> ExitCode {
+    let Some(day) = days::find(year, day) else {
+        eprintln!("No solution registered for {} day {}", year, day);
+        return ExitCode::FAILURE;
+    };
+    if let Some((name, _)) = params.iter().find(|(name, _)| !day.accepts(name)) {
+        eprintln!("{} day {} has no parameter {}", day.year, day.day, name);
+        return ExitCode::FAILURE;
+    }
+    report(run_day(day, source, params, limits, format))
+}
+
+/// Reads the input of a day and the parameters of its parts, for solving it in a batch.
+fn job(day: &'static Day, source: &Source, overrides: &Params) -> Job {
+    let dir = day_dir(day.year, day.day);
+    let setup = if source.path(&dir).is_some_and(|path| !path.exists()) {
+        Err(Status::NoInput)
+    } else {
+        read_input(day, source)
+            .and_then(|content| {
+                let params = [
+                    params(day, source, Part::One, overrides)?,
+                    params(day, source, Part::Two, overrides)?,
+                ];
+                Ok((content, params))
+            })
+            .map_err(Status::Failed)
+    };
+    Job { day, setup }
+}
+
+/// Returns the command that solves a part in a child process, with its parameters.
+fn solve_part_command(exe: &Path, day: &Day, part: Part, params: &Params) -> process::Command {
+    let mut command = process::Command::new(exe);
+    command.args([
+        "solve-part".to_string(),
+        day.year.to_string(),
+        day.day.to_string(),
+        part.to_string(),
+    ]);
+    for (name, value) in params.iter() {
+        command.arg(format!("--param={}={}", name, value));
+    }
+    command
+}
+
+/// Solves the parts of the jobs, each in a child process running this binary within `limits`.
+fn run_in_children(jobs: Vec<Job>, workers: usize, limits: Limits) -> Result<Vec<Run>, String> {
+    let exe = env::current_exe().map_err(|e| format!("Could not find the aoc binary: {}", e))?;
+    Ok(batch::run_all(
+        jobs,
+        workers,
+        limits,
+        |day, part, params| solve_part_command(&exe, day, part, params),
+    ))
+}
+
+/// Returns the limits of a part given on the command line, where a memory of 0 means no limit.
+fn limits(timeout: u64, memory: u64) -> Limits {
+    Limits {
+        timeout: Duration::from_secs(timeout),
+        memory: (memory > 0).then_some(memory << 20),
+    }
+}
+
+/// Returns the number of parts to solve at once, by default one per CPU.
+fn workers(jobs: Option<usize>) -> usize {
+    jobs.unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()))
+}
+
+/// Solves a day against the input of every account in its `inputs` directory, and compares the
+/// answers with those recorded per account.
+fn check_accounts(year: u16, day: u8, workers: usize, limits: Limits) -> Result<(), String> {
+    let day = days::find(year, day)
+        .ok_or_else(|| format!("No solution registered for {} day {}", year, day))?;
+    let accounts = accounts::list(&day_dir(day.year, day.day))?;
+    if accounts.is_empty() {
+        return Err(format!(
+            "No inputs in {}",
+            day_dir(day.year, day.day).join(accounts::DIR).display()
+        ));
+    }
+    let jobs = accounts
+        .iter()
+        .map(|(_, dir)| job(day, &accounts::source(dir), &Params::default()))
+        .collect();
+    let runs = run_in_children(jobs, workers, limits)?;
+    let reports = accounts::compare(&accounts, &runs)?;
+    print!("{}", accounts::render(day, &reports));
+
+    let broken: Vec<&str> = reports
+        .iter()
+        .filter(|(_, outcomes)| outcomes.iter().any(|outcome| outcome.is_failure()))
+        .map(|(name, _)| name.as_str())
+        .collect();
+    match broken.as_slice() {
+        [] => Ok(()),
+        broken => Err(format!(
+            "The solution fails for the input of {}",
+            broken.join(", ")
+        )),
+    }
+}
+
+/// Solves every registered day, or those of a year, in parallel and prints a summary. Each part
+/// runs in a child process within `limits`. Fails if any part panicked, timed out, ran out of
+/// memory or couldn't be solved.
+fn run_all(
+    year: Option<u16>,
+    source: &Source,
+    params: &Params,
+    workers: usize,
+    limits: Limits,
+    format: Format,
+) -> ExitCode {
+    let jobs: Vec<Job> = days::DAYS
+        .iter()
+        .filter(|day| year.is_none_or(|year| day.year == year))
+        .map(|day| job(day, source, params))
+        .collect();
+    if jobs.is_empty() {
+        eprintln!("No solutions registered for {}", year.unwrap_or_default());
+        return ExitCode::FAILURE;
+    }
+    let start = Instant::now();
+    let runs = match run_in_children(jobs, workers, limits) {
+        Ok(runs) => runs,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    match format {
+        Format::Human => {
+            print!("{}", batch::render(&runs));
+            let solved = runs
+                .iter()
+                .filter(|run| matches!(run.status, Status::Solved(_)))
+                .count();
+            println!(
+                "\nSolved {} of {} parts in {:.2}s",
+                solved,
+                runs.len(),
+                start.elapsed().as_secs_f64()
+            );
+        }
+        Format::Json => print!("{}", output::json(&runs)),
+        Format::Tsv => print!("{}", output::tsv(&runs)),
+    }
+    if runs.iter().any(|run| run.status.is_failure()) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn fetch(year: u16, day: u8) -> Result<(), String> {
+    let path = day_dir(year, day).join("input");
+    let client = Client::from_config(&Config::load()?)?;
+    match fetch::fetch_input(&client, year, day, &path)? {
+        Fetched::Downloaded => println!("Downloaded {}", path.display()),
+        Fetched::Cached => println!("{} already exists", path.display()),
+    }
+    Ok(())
+}
+
+fn submit(year: u16, day: u8, part: Part) -> Result<(), String> {
+    let day = days::find(year, day)
+        .ok_or_else(|| format!("No solution registered for {} day {}", year, day))?;
+    let source = own_input(day);
+    let content = read_input(day, &source)?;
+    let input = day.parse(&content).map_err(|e| e.render(&content))?;
+    let params = params(day, &source, part, &Params::default())?;
+    let answer = day.solve_part(input.as_ref(), part, &params);
+    println!("Solution part {}: {}", part, answer);
+
+    let mut ledger = Ledger::load(&day_dir(day.year, day.day).join("ledger.jsonl"))?;
+    let client = Client::from_config(&Config::load()?)?;
+    let response = submit::submit(&client, &mut ledger, day.year, day.day, part, &answer)?;
+    println!("{}", response.message);
+    match response.verdict {
+        Verdict::Correct => {
+            let dir = day_dir(day.year, day.day);
+            let mut answers = Answers::load(&dir)?;
+            answers.set(part, &answer);
+            answers.save(&dir)
+        }
+        verdict => Err(format!("The answer was not accepted: {}", verdict)),
+    }
+}
+
+fn read_page(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("Could not read {}: {}", path.display(), e))
+}
+
+fn new_day(year: u16, day: u8, page: Option<&Path>) -> Result<(), String> {
+    let page = page.map(read_page).transpose()?;
+    for note in scaffold::create(root(), year, day, page.as_deref())? {
+        eprintln!("{}", note);
+    }
+    println!("Created {}", day_dir(year, day).display());
+    Ok(())
+}
+
+fn examples(year: u16, day: u8, page: &Path) -> Result<(), String> {
+    let dir = day_dir(year, day);
+    if !dir.is_dir() {
+        return Err(format!("{} does not exist", dir.display()));
+    }
+    let (manifest, notes) = Puzzle::parse(&read_page(page)?)?.save_examples(&dir)?;
+    for note in notes {
+        eprintln!("{}", note);
+    }
+    for example in &manifest.examples {
+        let answers: Vec<String> = Part::ALL
+            .into_iter()
+            .filter_map(|part| Some(format!("part {}: {}", part, example.answer(part)?)))
+            .collect();
+        println!("{} ({})", example.input, answers.join(", "));
+    }
+    println!("Wrote {}", dir.join(manifest::FILE_NAME).display());
+    Ok(())
+}
+
+/// Checks a day against its recorded answers, solving each part in a child process within
+/// `limits`.
+fn verify_day(day: &Day, limits: Limits) -> Result<verify::Report, String> {
+    let exe = env::current_exe().map_err(|e| format!("Could not find the aoc binary: {}", e))?;
+    verify::verify_day(day, &day_dir(day.year, day.day), |input, part, params| {
+        let command = solve_part_command(&exe, day, part, params);
+        batch::run_child(command, input, &limits).0
+    })
+}
+
+fn verify(year: Option<u16>, limits: Limits) -> Result<(), String> {
+    let reports = days::DAYS
+        .iter()
+        .filter(|day| year.is_none_or(|year| day.year == year))
+        .map(|day| Ok((day, verify_day(day, limits)?)))
+        .collect::<Result<Vec<_>, String>>()?;
+    print!("{}", verify::render(&reports));
+
+    let outcomes: Vec<&Outcome> = reports
+        .iter()
+        .filter_map(|(_, report)| report.as_ref())
+        .flatten()
+        .collect();
+    let failed = outcomes
+        .iter()
+        .filter(|outcome| matches!(outcome, Outcome::Fail { .. }))
+        .count();
+    println!(
+        "{} passed, {} failed, {} missing, {} days without input",
+        outcomes.iter().filter(|o| **o == &Outcome::Pass).count(),
+        failed,
+        outcomes.iter().filter(|o| **o == &Outcome::Missing).count(),
+        reports
+            .iter()
+            .filter(|(_, report)| report.is_none())
+            .count()
+    );
+    match failed {
+        0 => Ok(()),
+        failed => Err(format!("{} answers differ from the recorded ones", failed)),
+    }
+}
+
+fn leaderboard(
+    year: u16,
+    day: Option<u8>,
+    file: Option<&Path>,
+    id: Option<u64>,
+) -> Result<(), String> {
+    let json = match file {
+        Some(path) => read_page(path)?,
+        None => {
+            let config = Config::load()?;
+            let id = id
+                .or(config.leaderboard())
+                .ok_or("No leaderboard given, pass --id or set `leaderboard` in the config")?;
+            let client = Client::from_config(&config)?;
+            leaderboard::load_cached(&client, year, id, &config.leaderboard_cache(year, id))?
+        }
+    };
+    let leaderboard = Leaderboard::parse(&json)?;
+    if leaderboard.year != year {
+        return Err(format!(
+            "The leaderboard is of {}, not {}",
+            leaderboard.year, year
+        ));
+    }
+    let days = match day {
+        Some(day) => vec![day],
+        None => leaderboard.days(),
+    };
+    for (i, day) in days.into_iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        let ledger = Ledger::load(&day_dir(year, day).join("ledger.jsonl"))?;
+        let solved = Part::ALL.map(|part| ledger.solved_at(part));
+        print!("{}", leaderboard.render_day(day, solved));
+    }
+    Ok(())
+}
+
+struct BenchArgs {
+    year: Option<u16>,
+    day: Option<u8>,
+    options: bench::Options,
+    limits: Limits,
+    save: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    threshold: f64,
+}
+
+fn bench(args: BenchArgs) -> Result<(), String> {
+    let baseline = args.baseline.as_deref().map(bench::load).transpose()?;
+    let mut inputs = vec![];
+    for day in days::DAYS.iter().filter(|day| {
+        args.year.is_none_or(|year| day.year == year) && args.day.is_none_or(|d| day.day == d)
+    }) {
+        // A single day should have an input; when timing many, skip those that don't
+        if args.day.is_none() && !day_dir(day.year, day.day).join("input").exists() {
+            continue;
+        }
+        let content = read_input(day, &own_input(day))?;
+        let params = [
+            params(day, &Source::Default, Part::One, &Params::default())?,
+            params(day, &Source::Default, Part::Two, &Params::default())?,
+        ];
+        inputs.push((day, content, params));
+    }
+    if inputs.is_empty() {
+        return Err("Found no days with an input to time".to_string());
+    }
+
+    // Solve each part once within the limits first, and only time the parts that finish
+    eprintln!("Checking that every part finishes within the limits");
+    let jobs = inputs
+        .iter()
+        .map(|(day, content, params)| Job {
+            day,
+            setup: Ok((content.clone(), params.clone())),
+        })
+        .collect();
+    let runs = run_in_children(jobs, workers(None), args.limits)?;
+    let mut records = vec![];
+    for (day, content, params) in inputs {
+        let parts: Vec<Part> = runs
+            .iter()
+            .filter(|run| (run.year, run.day) == (day.year, day.day))
+            .filter(|run| matches!(run.status, Status::Solved(_)))
+            .map(|run| run.part)
+            .collect();
+        // Parsing may be what doesn't finish
+        if parts.is_empty() {
+            continue;
+        }
+        eprintln!("Timing {} day {}", day.year, day.day);
+        records.extend(
+            bench::bench_day(day, &content, &params, &parts, args.options)
+                .map_err(|e| format!("{} day {}: {}", day.year, day.day, e.render(&content)))?,
+        );
+    }
+
+    let baseline = baseline.unwrap_or_default();
+    let threshold = args.threshold / 100.0;
+    print!("{}", bench::render(&records, &baseline, threshold));
+    for run in runs.iter().filter(|run| run.status.is_failure()) {
+        let label = format!("{} {:>2} part {}", run.year, run.day, run.part);
+        println!("{:<16}{:>10}", label, run.status.label());
+    }
+    if let Some(path) = &args.save {
+        bench::save(path, &records)?;
+    }
+    match bench::regressions(&records, &baseline, threshold).len() {
+        0 => Ok(()),
+        slower => Err(format!(
+            "{} steps got more than {}% slower than the baseline",
+            slower, args.threshold
+        )),
+    }
+}
+
+fn update_stars(verify: bool, limits: Limits) -> Result<(), String> {
+    let mut years: Vec<u16> = fs::read_dir(root())
+        .map_err(|e| format!("Could not read {}: {}", root().display(), e))?
+        .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse().ok())
+        .filter(|year: &u16| stars::has_records(&root().join(year.to_string())))
+        .collect();
+    years.sort_unstable();
+
+    let mut rows = BTreeMap::new();
+    for year in years {
+        let mut reports = HashMap::new();
+        let row = stars::row(|day, part| {
+            let dir = day_dir(year, day);
+            if !stars::is_solved(&dir, part)? {
+                return Ok(false);
+            }
+            if !verify {
+                return Ok(true);
+            }
+            let Some(solution) = days::find(year, day) else {
+                return Ok(false);
+            };
+            let report = match reports.entry(day) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => entry.insert(verify_day(solution, limits)?),
+            };
+            Ok(matches!(report, Some(outcomes)
+                if outcomes[part.number() as usize - 1] == Outcome::Pass))
+        })?;
+        rows.insert(year, row);
+    }
+    if rows.is_empty() {
+        return Err("Found no recorded answers to count stars from".to_string());
+    }
+
+    let path = root().join("README.md");
+    let readme = fs::read_to_string(&path)
+        .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+    let updated = stars::update_readme(&readme, &rows)?;
+    if updated == readme {
+        println!("The stars are up to date");
+        return Ok(());
+    }
+    fs::write(&path, updated).map_err(|e| format!("Could not write {}: {}", path.display(), e))?;
+    let years: Vec<String> = rows.keys().map(u16::to_string).collect();
+    println!("Updated the stars of {}", years.join(", "));
+    Ok(())
+}
+
+/// Prints the error of a command, if any, and turns the result into an exit code.
+fn report(result: Result<(), String>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run {
+            year,
+            day,
+            all,
+            only_year,
+            jobs,
+            timeout,
+            memory,
+            input,
+            example,
+            params,
+            format,
+        } => {
+            let source = match (input, example) {
+                (Some(path), _) => Source::from_arg(&path),
+                (None, Some(n)) => Source::Example(n),
+                (None, None) => Source::Default,
+            };
+            let mut overrides = Params::default();
+            for (name, value) in &params {
+                overrides.set(name, value);
+            }
+            if all {
+                run_all(
+                    only_year,
+                    &source,
+                    &overrides,
+                    workers(jobs),
+                    limits(timeout, memory),
+                    format,
+                )
+            } else {
+                let year = year.expect("A year should be given without --all");
+                let day = day.expect("A day should be given without --all");
+                run(
+                    year,
+                    day,
+                    &source,
+                    &overrides,
+                    limits(timeout, memory),
+                    format,
+                )
+            }
+        }
+        Command::Fetch { year, day } => report(fetch(year, day)),
+        Command::Submit { year, day, part } => {
+            let part = Part::from_number(part).expect("The part should be 1 or 2");
+            report(submit(year, day, part))
+        }
+        Command::New { year, day, page } => report(new_day(year, day, page.as_deref())),
+        Command::Verify {
+            year,
+            timeout,
+            memory,
+        } => report(verify(year, limits(timeout, memory))),
+        Command::Stars {
+            verify,
+            timeout,
+            memory,
+        } => report(update_stars(verify, limits(timeout, memory))),
+        Command::Bench {
+            year,
+            day,
+            warmup,
+            samples,
+            save,
+            baseline,
+            threshold,
+            timeout,
+            memory,
+        } => report(bench(BenchArgs {
+            year,
+            day,
+            options: bench::Options { warmup, samples },
+            limits: limits(timeout, memory),
+            save,
+            baseline,
+            threshold,
+        })),
+        Command::Examples { year, day, page } => report(examples(year, day, &page)),
+        Command::Accounts {
+            year,
+            day,
+            jobs,
+            timeout,
+            memory,
+        } => report(check_accounts(
+            year,
+            day,
+            workers(jobs),
+            limits(timeout, memory),
+        )),
+        Command::Leaderboard {
+            year,
+            day,
+            file,
+            id,
+        } => report(leaderboard(year, day, file.as_deref(), id)),
+        Command::Watch {
+            year,
+            day,
+            timeout,
+            memory,
+        } => report(watch::watch(root(), year, day, limits(timeout, memory))),
+        Command::SolvePart {
+            year,
+            day,
+            part,
+            params,
+        } => {
+            let Some(day) = days::find(year, day) else {
+                eprintln!("No solution registered for {} day {}", year, day);
+                return ExitCode::FAILURE;
+            };
+            let part = Part::from_number(part).expect("The part should be 1 or 2");
+            let mut values = Params::default();
+            for (name, value) in &params {
+                values.set(name, value);
+            }
+            batch::solve_part(day, part, &values)
+        }
+    }
+}
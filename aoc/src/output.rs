@@ -0,0 +1,130 @@
+//! Prints the outcomes of runs as a table for people, or as records for scripts.
+
+use std::fmt::Write;
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::batch::{Run, Status};
+
+/// How to print the outcomes of runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+    /// Answers, or a table of answers and times for many days
+    #[default]
+    Human,
+    /// A JSON object per part and line
+    Json,
+    /// Tab-separated values with a header
+    Tsv,
+}
+
+/// The outcome of a part as printed for scripts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record<'a> {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// The answer, if the part was solved.
+    pub answer: Option<&'a str>,
+    pub duration_ns: u64,
+    /// What became of the part: solved, panicked, timeout, oom, error or no_input.
+    pub status: &'static str,
+    /// Why the part wasn't solved, if it went wrong.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl<'a> Record<'a> {
+    pub fn new(run: &'a Run) -> Self {
+        let (status, answer, message) = match &run.status {
+            Status::Solved(answer) => ("solved", Some(answer.as_str()), None),
+            Status::Panicked(message) => ("panicked", None, Some(message.clone())),
+            Status::TimedOut => ("timeout", None, None),
+            Status::OutOfMemory => ("oom", None, None),
+            Status::Failed(message) => ("error", None, Some(message.clone())),
+            Status::NoInput => ("no_input", None, None),
+        };
+        Self {
+            year: run.year,
+            day: run.day,
+            part: run.part.number(),
+            answer,
+            duration_ns: run.duration.as_nanos() as u64,
+            status,
+            message,
+        }
+    }
+}
+
+/// Renders a JSON object per run, each on its own line.
+pub fn json(runs: &[Run]) -> String {
+    runs.iter()
+        .map(|run| {
+            let line = serde_json::to_string(&Record::new(run)).expect("A record should serialize");
+            line + "\n"
+        })
+        .collect()
+}
+
+/// Renders a header and a row of tab-separated values per run. Tabs and newlines in answers
+/// are replaced by spaces, so that each run stays a single row.
+pub fn tsv(runs: &[Run]) -> String {
+    let mut rendered = String::from("year\tday\tpart\tstatus\tanswer\tduration_ns\n");
+    for run in runs {
+        let record = Record::new(run);
+        let answer = record.answer.unwrap_or_default().replace(['\t', '\n'], " ");
+        writeln!(
+            rendered,
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            record.year, record.day, record.part, record.status, answer, record.duration_ns
+        )
+        .unwrap();
+    }
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_runner::Part;
+    use std::time::Duration;
+
+    fn runs() -> Vec<Run> {
+        vec![
+            Run {
+                year: 2023,
+                day: 1,
+                part: Part::One,
+                status: Status::Solved("142".to_string()),
+                duration: Duration::from_micros(12),
+            },
+            Run {
+                year: 2023,
+                day: 1,
+                part: Part::Two,
+                status: Status::Panicked("Could not find route".to_string()),
+                duration: Duration::from_nanos(500),
+            },
+        ]
+    }
+
+    #[test]
+    fn renders_json_lines() {
+        assert_eq!(
+            json(&runs()),
+            "{\"year\":2023,\"day\":1,\"part\":1,\"answer\":\"142\",\"duration_ns\":12000,\"status\":\"solved\"}\n\
+             {\"year\":2023,\"day\":1,\"part\":2,\"answer\":null,\"duration_ns\":500,\"status\":\"panicked\",\"message\":\"Could not find route\"}\n"
+        );
+    }
+
+    #[test]
+    fn renders_tab_separated_values() {
+        assert_eq!(
+            tsv(&runs()),
+            "year\tday\tpart\tstatus\tanswer\tduration_ns\n\
+             2023\t1\t1\tsolved\t142\t12000\n\
+             2023\t1\t2\tpanicked\t\t500\n"
+        );
+    }
+}
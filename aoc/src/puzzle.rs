@@ -0,0 +1,438 @@
+//! Reads the examples and their expected answers from a saved puzzle page.
+
+use std::fs;
+use std::path::Path;
+
+use aoc_runner::manifest::{Example, Manifest, PartParams, Value};
+use aoc_runner::Part;
+
+use crate::html;
+
+/// A `<pre><code>` block or an emphasized `<code>` value, in the order they appear.
+#[derive(Debug, PartialEq, Eq)]
+enum Item {
+    /// A block of text. Blocks with emphasized text illustrate the solution instead of giving an
+    /// input.
+    Block { text: String, illustration: bool },
+    /// An emphasized value. Those in the paragraph that introduces a block, as in "here is a
+    /// situation that takes 6 steps:", are about that block rather than the one before.
+    Answer { value: String, lead_in: bool },
+}
+
+/// The description of one part of a puzzle.
+#[derive(Debug, PartialEq, Eq)]
+struct Article {
+    items: Vec<Item>,
+}
+
+impl Article {
+    fn parse(article: &str) -> Self {
+        // Answers are written as <code><em>..</em></code>, and sometimes the other way around
+        let article = article
+            .replace("<em><code>", "<code><em>")
+            .replace("</code></em>", "</em></code>");
+
+        let mut items = vec![];
+        let mut rest = article.as_str();
+        loop {
+            let (start, tag) = match (rest.find("<pre>"), rest.find("<code><em>")) {
+                (Some(block), Some(answer)) if block < answer => (block, "pre"),
+                (Some(block), None) => (block, "pre"),
+                (_, Some(answer)) => (answer, "code"),
+                (None, None) => break,
+            };
+            rest = &rest[start..];
+            let Some(&content) = html::elements(rest, tag).first() else {
+                break;
+            };
+            // The content is a slice of rest, so this is where the element ends
+            let end = content.as_ptr() as usize - rest.as_ptr() as usize + content.len();
+            rest = &rest[end + format!("</{}>", tag).len()..];
+
+            if tag == "pre" {
+                let code = content.trim();
+                let code = code.strip_prefix("<code>").unwrap_or(code);
+                let code = code.strip_suffix("</code>").unwrap_or(code);
+                items.push(Item::Block {
+                    text: html::text(code),
+                    illustration: code.contains("<em>"),
+                });
+            } else if let Some(value) = content
+                .strip_prefix("<em>")
+                .and_then(|content| content.strip_suffix("</em>"))
+                .filter(|value| !value.contains('<'))
+            {
+                let lead_in = rest.find("<pre>").is_some_and(|block| {
+                    !rest[..block].contains("<p") && !rest[..block].contains("<li")
+                });
+                items.push(Item::Answer {
+                    value: html::text(value),
+                    lead_in,
+                });
+            }
+        }
+        Self { items }
+    }
+
+    /// Returns the example inputs of this part, each with the last value emphasized about it,
+    /// which is where the puzzle text concludes what the answer for it is. Blocks that start with
+    /// a line of an earlier example are worked out versions of it rather than examples.
+    fn examples<'a>(&'a self, earlier: &[&str]) -> Vec<(&'a str, Option<&'a str>)> {
+        let collapse = |line: &str| line.split_whitespace().collect::<Vec<_>>().join(" ");
+        let mut lines: Vec<String> = earlier
+            .iter()
+            .flat_map(|example| example.lines().map(collapse))
+            .collect();
+        let is_example: Vec<bool> = self
+            .items
+            .iter()
+            .map(|item| match item {
+                Item::Block {
+                    text,
+                    illustration: false,
+                } => {
+                    let first = collapse(text.lines().next().unwrap_or_default());
+                    let repeated = earlier.contains(&text.as_str());
+                    let derived = !repeated && lines.contains(&first);
+                    lines.extend(text.lines().map(collapse));
+                    !derived
+                }
+                _ => false,
+            })
+            .collect();
+
+        let mut examples: Vec<(&str, Option<&str>)> = vec![];
+        let mut pending = None;
+        for (i, item) in self.items.iter().enumerate() {
+            match item {
+                Item::Block { text, .. } if is_example[i] => {
+                    examples.push((text, pending.take()));
+                }
+                Item::Block { .. } => {}
+                Item::Answer { value, lead_in } => {
+                    let next_block = (i..self.items.len())
+                        .find(|&j| matches!(self.items[j], Item::Block { .. }));
+                    if *lead_in && next_block.is_some_and(|j| is_example[j]) {
+                        pending = Some(value.as_str());
+                    } else if let Some(example) = examples.last_mut() {
+                        example.1 = Some(value);
+                    }
+                }
+            }
+        }
+        examples
+    }
+
+    /// Returns the last emphasized value of this part.
+    fn final_answer(&self) -> Option<&str> {
+        self.items.iter().rev().find_map(|item| match item {
+            Item::Answer { value, .. } => Some(value.as_str()),
+            _ => None,
+        })
+    }
+}
+
+/// An example input with the answers the puzzle text gives for it.
+#[derive(Debug, PartialEq, Eq)]
+pub struct PairedExample {
+    pub input: String,
+    pub answers: [Option<String>; 2],
+}
+
+/// The parts of a puzzle that are unlocked on a saved page.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Puzzle {
+    articles: Vec<Article>,
+}
+
+impl Puzzle {
+    pub fn parse(page: &str) -> Result<Self, String> {
+        let articles: Vec<Article> = html::elements(page, "article")
+            .into_iter()
+            .map(Article::parse)
+            .collect();
+        if articles.is_empty() {
+            return Err("The page has no puzzle description".to_string());
+        }
+        Ok(Self { articles })
+    }
+
+    /// Returns the examples that have an answer. The first one has an answer to part 1, and the
+    /// first one with an answer to part 2 comes right after it, unless it's the same example.
+    pub fn examples(&self) -> Vec<PairedExample> {
+        let mut paired: Vec<PairedExample> = vec![];
+        for (part, article) in Part::ALL.into_iter().zip(&self.articles) {
+            let index = part.number() as usize - 1;
+            let earlier: Vec<&str> = paired.iter().map(|p| p.input.as_str()).collect();
+            let examples = article.examples(&earlier);
+            if examples.is_empty() {
+                // A part without examples of its own reuses the first one
+                if let (Some(first), Some(answer)) = (paired.first_mut(), article.final_answer()) {
+                    first.answers[index].get_or_insert_with(|| answer.to_string());
+                }
+                continue;
+            }
+            for (input, answer) in examples {
+                let position = match paired.iter().position(|p| p.input == input) {
+                    Some(position) => position,
+                    None => {
+                        paired.push(PairedExample {
+                            input: input.to_string(),
+                            answers: [None, None],
+                        });
+                        paired.len() - 1
+                    }
+                };
+                if let Some(answer) = answer {
+                    paired[position].answers[index].get_or_insert_with(|| answer.to_string());
+                }
+            }
+        }
+
+        paired.retain(|example| example.answers.iter().any(Option::is_some));
+        for index in [1, 0] {
+            if let Some(position) = paired.iter().position(|p| p.answers[index].is_some()) {
+                let example = paired.remove(position);
+                paired.insert(0, example);
+            }
+        }
+        paired
+    }
+
+    /// Writes the examples to `input-example-N` files in `dir` and lists them in its manifest.
+    /// Their answers are merged into the examples listed before with the same input, which keep
+    /// their parameters and move along when the numbering changes, such as when part 2 unlocks.
+    /// Returns the manifest with notes on example files the page no longer has.
+    pub fn save_examples(&self, dir: &Path) -> Result<(Manifest, Vec<String>), String> {
+        let mut manifest = Manifest::load(dir)?;
+        // What the listed examples were about, before their files are overwritten
+        let inputs: Vec<Option<String>> = manifest
+            .examples
+            .iter()
+            .map(|listed| fs::read_to_string(dir.join(&listed.input)).ok())
+            .collect();
+        let examples = self.examples();
+        let mut answers = vec![];
+        for (i, example) in examples.into_iter().enumerate() {
+            let file = format!("input-example-{}", i + 1);
+            let path = dir.join(&file);
+            fs::write(&path, &example.input)
+                .map_err(|e| format!("Could not write {}: {}", path.display(), e))?;
+            answers.push((file, example.input, Some(example.answers)));
+        }
+
+        let mut merged = vec![];
+        for (mut listed, before) in manifest.examples.into_iter().zip(inputs) {
+            let new =
+                before.and_then(|before| answers.iter_mut().find(|(_, input, _)| *input == before));
+            if let Some((file, _, new)) = new {
+                listed.input = file.clone();
+                // Examples listed more than once, with different parameters, are only updated once
+                if let Some([part1, part2]) = new.take() {
+                    listed.part1 = part1.map(Value).or(listed.part1);
+                    listed.part2 = part2.map(Value).or(listed.part2);
+                }
+            } else if example_number(&listed.input).is_some() {
+                // The file now holds another example, or none that is on the page
+                continue;
+            }
+            merged.push(listed);
+        }
+        let count = answers.len();
+        for (input, _, new) in answers {
+            if let Some([part1, part2]) = new {
+                merged.push(Example {
+                    input,
+                    part1: part1.map(Value),
+                    part2: part2.map(Value),
+                    params: PartParams::default(),
+                });
+            }
+        }
+        // Stable, so that examples listed more than once stay in their order
+        merged.sort_by_key(|example| example_number(&example.input).unwrap_or(usize::MAX));
+        manifest.examples = merged;
+        manifest.save(dir)?;
+
+        let entries =
+            fs::read_dir(dir).map_err(|e| format!("Could not read {}: {}", dir.display(), e))?;
+        let mut stale: Vec<(usize, String)> = entries
+            .filter_map(|entry| {
+                let name = entry.ok()?.file_name().into_string().ok()?;
+                let n = example_number(&name).filter(|n| *n > count)?;
+                Some((n, name))
+            })
+            .collect();
+        stale.sort_unstable();
+        let notes = stale
+            .into_iter()
+            .map(|(_, name)| {
+                format!(
+                    "{} is not an example on the page, so it was left out of the manifest",
+                    dir.join(name).display()
+                )
+            })
+            .collect();
+        Ok((manifest, notes))
+    }
+}
+
+/// Returns the N of an `input-example-N` file.
+fn example_number(file: &str) -> Option<usize> {
+    file.strip_prefix("input-example-")?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NETWORK: &str = include_str!("../testdata/puzzle/network.html");
+    const HISTORIES: &str = include_str!("../testdata/puzzle/histories.html");
+
+    fn example(input: &str, part1: Option<&str>, part2: Option<&str>) -> PairedExample {
+        PairedExample {
+            input: input.to_string(),
+            answers: [part1.map(String::from), part2.map(String::from)],
+        }
+    }
+
+    #[test]
+    fn skips_illustrations() {
+        let puzzle = Puzzle::parse(HISTORIES).unwrap();
+        assert_eq!(
+            puzzle.examples(),
+            [example("1 3 5 7\n2 2 2 2\n", Some("11"), Some("1"))]
+        );
+    }
+
+    #[test]
+    fn pairs_each_example_with_its_answer() {
+        let examples = Puzzle::parse(NETWORK).unwrap().examples();
+        let answers: Vec<_> = examples
+            .iter()
+            .map(|example| (example.input.lines().next().unwrap(), &example.answers))
+            .collect();
+        assert_eq!(
+            answers,
+            [
+                ("RL", &[Some("2".to_string()), None]),
+                ("LR", &[None, Some("6".to_string())]),
+                ("LLR", &[Some("6".to_string()), None]),
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_locked_second_part() {
+        let first_part = &HISTORIES[..HISTORIES
+            .find("<article class=\"day-desc\"><h2 id=\"part2\">")
+            .unwrap()];
+        let examples = Puzzle::parse(first_part).unwrap().examples();
+        assert_eq!(examples.len(), 1);
+        assert_eq!(examples[0].answers, [Some("11".to_string()), None]);
+    }
+
+    #[test]
+    fn decodes_examples() {
+        let page = "<article><pre><code>a &lt;-&gt; b\n</code></pre>\
+                    <p><em><code>42</code></em></p></article>";
+        let puzzle = Puzzle::parse(page).unwrap();
+        assert_eq!(puzzle.examples(), [example("a <-> b\n", Some("42"), None)]);
+    }
+
+    #[test]
+    fn rejects_pages_without_puzzle() {
+        assert!(Puzzle::parse("<html><body>404 Not Found</body></html>").is_err());
+    }
+
+    #[test]
+    fn saves_examples_to_manifest() {
+        let dir = tempfile::tempdir().unwrap();
+        let (manifest, notes) = Puzzle::parse(NETWORK)
+            .unwrap()
+            .save_examples(dir.path())
+            .unwrap();
+        assert!(notes.is_empty());
+        assert_eq!(Manifest::load(dir.path()), Ok(manifest));
+        assert_eq!(
+            fs::read_to_string(dir.path().join("day.toml")).unwrap(),
+            "[[example]]\ninput = \"input-example-1\"\npart1 = 2\n\n\
+             [[example]]\ninput = \"input-example-2\"\npart2 = 6\n\n\
+             [[example]]\ninput = \"input-example-3\"\npart1 = 6\n"
+        );
+        assert!(fs::read_to_string(dir.path().join("input-example-2"))
+            .unwrap()
+            .starts_with("LR\n"));
+    }
+
+    #[test]
+    fn keeps_parameters_of_listed_examples() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("day.toml"),
+            "[[example]]\ninput = \"input-example-1\"\npart2 = 3\n\n\
+             [[example]]\ninput = \"input-example-2\"\npart1 = 5\n\n\
+             [example.params.part2]\nsteps = 6\n\n\
+             [[example]]\ninput = \"input-example-4\"\npart1 = 1\n",
+        )
+        .unwrap();
+        let examples = Puzzle::parse(NETWORK).unwrap().examples();
+        for (i, example) in examples[..2].iter().enumerate() {
+            let path = dir.path().join(format!("input-example-{}", i + 1));
+            fs::write(path, &example.input).unwrap();
+        }
+        fs::write(dir.path().join("input-example-4"), "1\n").unwrap();
+
+        let (_, notes) = Puzzle::parse(NETWORK)
+            .unwrap()
+            .save_examples(dir.path())
+            .unwrap();
+        assert_eq!(
+            fs::read_to_string(dir.path().join("day.toml")).unwrap(),
+            "[[example]]\ninput = \"input-example-1\"\npart1 = 2\npart2 = 3\n\n\
+             [[example]]\ninput = \"input-example-2\"\npart1 = 5\npart2 = 6\n\n\
+             [example.params.part2]\nsteps = 6\n\n\
+             [[example]]\ninput = \"input-example-3\"\npart1 = 6\n"
+        );
+        assert_eq!(notes.len(), 1);
+        assert!(notes[0].contains("input-example-4"));
+    }
+
+    #[test]
+    fn follows_examples_that_move_when_part_two_unlocks() {
+        let dir = tempfile::tempdir().unwrap();
+        let locked = &NETWORK[..NETWORK
+            .find("<article class=\"day-desc\"><h2 id=\"part2\">")
+            .unwrap()];
+        let (mut manifest, _) = Puzzle::parse(locked)
+            .unwrap()
+            .save_examples(dir.path())
+            .unwrap();
+        assert_eq!(
+            fs::read_to_string(dir.path().join("day.toml")).unwrap(),
+            "[[example]]\ninput = \"input-example-1\"\npart1 = 2\n\n\
+             [[example]]\ninput = \"input-example-2\"\npart1 = 6\n"
+        );
+        manifest.examples[1]
+            .params
+            .part1
+            .insert("steps".to_string(), Value("6".to_string()));
+        manifest.save(dir.path()).unwrap();
+
+        let (_, notes) = Puzzle::parse(NETWORK)
+            .unwrap()
+            .save_examples(dir.path())
+            .unwrap();
+        assert!(notes.is_empty());
+        assert_eq!(
+            fs::read_to_string(dir.path().join("day.toml")).unwrap(),
+            "[[example]]\ninput = \"input-example-1\"\npart1 = 2\n\n\
+             [[example]]\ninput = \"input-example-2\"\npart2 = 6\n\n\
+             [[example]]\ninput = \"input-example-3\"\npart1 = 6\n\n\
+             [example.params.part1]\nsteps = 6\n"
+        );
+        assert!(fs::read_to_string(dir.path().join("input-example-3"))
+            .unwrap()
+            .starts_with("LLR\n"));
+    }
+}
@@ -0,0 +1,291 @@
+//! Creates the crate of a new day from the `day_X` template of its year.
+
+use std::fs;
+use std::path::Path;
+
+use aoc_runner::Part;
+
+use crate::puzzle::Puzzle;
+
+/// Parses keys such as the `2023` and `9` in `aoc-2023-day-9`, given the text before the year
+/// and the text between the year and the day.
+fn year_day(text: &str, prefix: &str, separator: &str) -> Option<(u16, u8)> {
+    let rest = text.strip_prefix(prefix)?;
+    let (year, rest) = rest.split_once(separator)?;
+    let day: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// Inserts a line among the lines that have a key, keeping those sorted by key.
+fn insert_sorted<K: Ord>(
+    content: &str,
+    line: &str,
+    key: impl Fn(&str) -> Option<K>,
+) -> Result<String, String> {
+    let new_key = key(line).expect("The inserted line should have a key");
+    let lines: Vec<&str> = content.lines().collect();
+    let keyed: Vec<(usize, K)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| key(line).map(|k| (i, k)))
+        .collect();
+    if keyed.iter().any(|(_, k)| *k == new_key) {
+        return Err(format!("{} is already registered", line.trim()));
+    }
+    let position = match keyed.iter().rev().find(|(_, k)| *k < new_key) {
+        Some((i, _)) => i + 1,
+        None => keyed.first().ok_or("Found no lines to insert after")?.0,
+    };
+
+    let mut lines = lines;
+    lines.insert(position, line);
+    let mut content = lines.join("\n");
+    content.push('\n');
+    Ok(content)
+}
+
+fn update(path: &Path, edit: impl FnOnce(&str) -> Result<String, String>) -> Result<(), String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+    let content =
+        edit(&content).map_err(|e| format!("Could not update {}: {}", path.display(), e))?;
+    fs::write(path, content).map_err(|e| format!("Could not write {}: {}", path.display(), e))
+}
+
+/// Copies a directory, replacing the template's names in every file.
+fn copy_template(from: &Path, to: &Path, year: u16, day: u8) -> Result<(), String> {
+    fs::create_dir_all(to).map_err(|e| format!("Could not create {}: {}", to.display(), e))?;
+    let entries =
+        fs::read_dir(from).map_err(|e| format!("Could not read {}: {}", from.display(), e))?;
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("Could not read {}: {}", from.display(), e))?;
+        let name = entry.file_name();
+        if name == "target" || name == "Cargo.lock" {
+            continue;
+        }
+        let (from, to) = (entry.path(), to.join(&name));
+        if from.is_dir() {
+            copy_template(&from, &to, year, day)?;
+            continue;
+        }
+        let content = fs::read_to_string(&from)
+            .map_err(|e| format!("Could not read {}: {}", from.display(), e))?
+            .replace(
+                &format!("aoc-{}-day-X", year),
+                &format!("aoc-{}-day-{}", year, day),
+            )
+            .replace(
+                &format!("aoc_{}_day_X", year),
+                &format!("aoc_{}_day_{}", year, day),
+            )
+            .replace("DayX", &format!("Day{}", day))
+            .replace("const DAY: u8 = 0;", &format!("const DAY: u8 = {};", day));
+        fs::write(&to, content).map_err(|e| format!("Could not write {}: {}", to.display(), e))?;
+    }
+    Ok(())
+}
+
+/// Writes the examples of a saved puzzle page and their manifest, from which the tests of the
+/// day are generated.
+fn fill_in_examples(day_dir: &Path, page: &str) -> Result<Vec<String>, String> {
+    let (manifest, mut notes) = Puzzle::parse(page)?.save_examples(day_dir)?;
+    notes.extend(
+        Part::ALL
+            .into_iter()
+            .filter(|part| {
+                !manifest
+                    .examples
+                    .iter()
+                    .any(|example| example.answer(*part).is_some())
+            })
+            .map(|part| format!("Found no example for part {}", part)),
+    );
+    Ok(notes)
+}
+
+/// Creates `YYYY/day_N` under `root` and registers it in the workspace and the runner. Returns
+/// notes on what could not be filled in from the puzzle page.
+pub fn create(root: &Path, year: u16, day: u8, page: Option<&str>) -> Result<Vec<String>, String> {
+    let year_dir = root.join(year.to_string());
+    let day_dir = year_dir.join(format!("day_{}", day));
+    let template = year_dir.join("day_X").join("solution");
+    if day_dir.join("solution").exists() {
+        return Err(format!(
+            "{} already exists",
+            day_dir.join("solution").display()
+        ));
+    }
+    if !template.is_dir() {
+        return Err(format!("There is no template at {}", template.display()));
+    }
+
+    // Check the puzzle page before creating anything
+    if let Some(page) = page {
+        Puzzle::parse(page)?;
+    }
+
+    copy_template(&template, &day_dir.join("solution"), year, day)?;
+    let notes = match page {
+        Some(page) => fill_in_examples(&day_dir, page)?,
+        None => vec![],
+    };
+
+    update(&root.join("Cargo.toml"), |content| {
+        let year_of = |prefix: &str| {
+            let prefix = prefix.to_string();
+            move |line: &str| {
+                line.trim()
+                    .strip_prefix('"')
+                    .and_then(|line| line.strip_suffix(&prefix))
+                    .and_then(|year| year.parse::<u16>().ok())
+            }
+        };
+        let member = format!("    \"{}/day_*/solution\",", year);
+        let template = format!("    \"{}/day_X/solution\",", year);
+        if content.contains(member.trim()) {
+            return Ok(content.to_string());
+        }
+        let content = insert_sorted(content, &member, year_of("/day_*/solution\","))?;
+        insert_sorted(&content, &template, year_of("/day_X/solution\","))
+    })?;
+
+    update(&root.join("aoc").join("Cargo.toml"), |content| {
+        let line = format!(
+            "aoc-{0}-day-{1} = {{ path = \"../{0}/day_{1}/solution\" }}",
+            year, day
+        );
+        insert_sorted(content, &line, |line| year_day(line, "aoc-", "-day-"))
+    })?;
+
+    update(&root.join("aoc").join("src").join("days.rs"), |content| {
+        let line = format!("    Day::of::<aoc_{0}_day_{1}::Day{1}>(),", year, day);
+        insert_sorted(content, &line, |line| {
+            year_day(line.trim(), "Day::of::<aoc_", "_day_")
+        })
+    })?;
+
+    Ok(notes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = include_str!("../testdata/puzzle/histories.html");
+
+    /// Creates a repository with a template and two registered days.
+    fn repository() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        let write = |path: &str, content: &str| {
+            let path = root.path().join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        };
+        write(
+            "Cargo.toml",
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"2023/day_*/solution\",\n]\n\
+             exclude = [\n    \"2023/day_X/solution\",\n]\n",
+        );
+        write(
+            "aoc/Cargo.toml",
+            "[dependencies]\nclap = \"4\"\n\
+             aoc-2023-day-1 = { path = \"../2023/day_1/solution\" }\n\
+             aoc-2023-day-10 = { path = \"../2023/day_10/solution\" }\n",
+        );
+        write(
+            "aoc/src/days.rs",
+            "pub const DAYS: &[Day] = &[\n    Day::of::<aoc_2023_day_1::Day1>(),\n    \
+             Day::of::<aoc_2023_day_10::Day10>(),\n];\n",
+        );
+        for year in ["2015", "2023"] {
+            write(
+                &format!("{}/day_X/solution/Cargo.toml", year),
+                &format!("[package]\nname = \"aoc-{}-day-X\"\n", year),
+            );
+            write(
+                &format!("{}/day_X/solution/src/main.rs", year),
+                &format!("aoc_runner::main::<aoc_{}_day_X::DayX>();\n", year),
+            );
+            write(
+                &format!("{}/day_X/solution/src/lib.rs", year),
+                "const DAY: u8 = 0;\naoc_runner::example_tests!(DayX);\n",
+            );
+        }
+        root
+    }
+
+    fn read(root: &tempfile::TempDir, path: &str) -> String {
+        fs::read_to_string(root.path().join(path)).unwrap()
+    }
+
+    #[test]
+    fn creates_day_from_template() {
+        let root = repository();
+        assert_eq!(create(root.path(), 2023, 9, None), Ok(vec![]));
+
+        assert_eq!(
+            read(&root, "2023/day_9/solution/Cargo.toml"),
+            "[package]\nname = \"aoc-2023-day-9\"\n"
+        );
+        assert_eq!(
+            read(&root, "2023/day_9/solution/src/main.rs"),
+            "aoc_runner::main::<aoc_2023_day_9::Day9>();\n"
+        );
+        assert!(read(&root, "2023/day_9/solution/src/lib.rs").starts_with("const DAY: u8 = 9;"));
+        assert!(read(&root, "aoc/Cargo.toml").contains(
+            "aoc-2023-day-1 = { path = \"../2023/day_1/solution\" }\n\
+             aoc-2023-day-9 = { path = \"../2023/day_9/solution\" }\n\
+             aoc-2023-day-10"
+        ));
+        assert!(read(&root, "aoc/src/days.rs").contains(
+            "Day1>(),\n    Day::of::<aoc_2023_day_9::Day9>(),\n    Day::of::<aoc_2023_day_10"
+        ));
+        assert!(!read(&root, "Cargo.toml").contains("2015"));
+    }
+
+    #[test]
+    fn registers_new_year_in_workspace() {
+        let root = repository();
+        create(root.path(), 2015, 3, None).unwrap();
+        assert_eq!(
+            read(&root, "Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"2015/day_*/solution\",\n    \
+             \"2023/day_*/solution\",\n]\nexclude = [\n    \"2015/day_X/solution\",\n    \
+             \"2023/day_X/solution\",\n]\n"
+        );
+        assert!(read(&root, "aoc/src/days.rs")
+            .contains("[\n    Day::of::<aoc_2015_day_3::Day3>(),\n    Day::of::<aoc_2023_day_1"));
+    }
+
+    #[test]
+    fn fills_in_examples_from_page() {
+        let root = repository();
+        assert_eq!(create(root.path(), 2023, 9, Some(PAGE)), Ok(vec![]));
+
+        assert_eq!(
+            read(&root, "2023/day_9/input-example-1"),
+            "1 3 5 7\n2 2 2 2\n"
+        );
+        assert!(!root.path().join("2023/day_9/input-example-2").exists());
+        assert_eq!(
+            read(&root, "2023/day_9/day.toml"),
+            "[[example]]\ninput = \"input-example-1\"\npart1 = 11\npart2 = 1\n"
+        );
+        assert_eq!(
+            read(&root, "2023/day_9/solution/src/lib.rs"),
+            "const DAY: u8 = 9;\naoc_runner::example_tests!(Day9);\n"
+        );
+    }
+
+    #[test]
+    fn refuses_existing_days() {
+        let root = repository();
+        create(root.path(), 2023, 9, None).unwrap();
+        assert!(create(root.path(), 2023, 9, None)
+            .unwrap_err()
+            .contains("already exists"));
+        assert!(create(root.path(), 2022, 1, None)
+            .unwrap_err()
+            .contains("no template"));
+    }
+}
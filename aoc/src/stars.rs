@@ -0,0 +1,144 @@
+//! Works out which parts are solved, and writes them to the star calendar in the README.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use aoc_runner::Part;
+
+use crate::answers::{self, Answers};
+use crate::ledger::Ledger;
+
+/// The number of days in an event, and of characters in a row of the calendar.
+const DAYS: u8 = 25;
+const ROW_WIDTH: usize = DAYS as usize * 2;
+
+/// Returns whether a part of the day in `dir` has an accepted answer, as recorded in its
+/// answers file or its ledger.
+pub fn is_solved(dir: &Path, part: Part) -> Result<bool, String> {
+    Ok(Answers::load(dir)?.get(part).is_some()
+        || Ledger::load(&dir.join("ledger.jsonl"))?
+            .correct_answer(part)
+            .is_some())
+}
+
+/// Returns whether anything was recorded for the days of a year, so its row can be trusted.
+pub fn has_records(year_dir: &Path) -> bool {
+    (1..=DAYS).any(|day| {
+        let dir = year_dir.join(format!("day_{}", day));
+        dir.join(answers::FILE_NAME).exists() || dir.join("ledger.jsonl").exists()
+    })
+}
+
+/// Renders the stars of a year, two characters per day.
+pub fn row(mut solved: impl FnMut(u8, Part) -> Result<bool, String>) -> Result<String, String> {
+    let mut row = String::new();
+    for day in 1..=DAYS {
+        for part in Part::ALL {
+            row.push(if solved(day, part)? { '*' } else { '.' });
+        }
+    }
+    Ok(row)
+}
+
+/// Replaces the star rows of the given years in the calendar at the top of the README, keeping
+/// everything around them as it is.
+pub fn update_readme(readme: &str, rows: &BTreeMap<u16, String>) -> Result<String, String> {
+    let mut lines: Vec<String> = readme.lines().map(str::to_string).collect();
+    let end = lines
+        .iter()
+        .skip(1)
+        .position(|line| line.starts_with("```"))
+        .ok_or("The README has no calendar block")?
+        + 1;
+    for (year, row) in rows {
+        assert_eq!(row.len(), ROW_WIDTH, "A row should have two stars per day");
+        let label = format!(" {} | ", year);
+        let line = lines[..=end]
+            .iter_mut()
+            .find(|line| line.contains(&label))
+            .ok_or_else(|| format!("The calendar has no row for {}", year))?;
+        let start = line.find(&label).unwrap() + label.len();
+        let stars = line.get(start..start + ROW_WIDTH).unwrap_or_default();
+        if stars.len() != ROW_WIDTH || !stars.chars().all(|c| c == '*' || c == '.') {
+            return Err(format!(
+                "The row of {} is not {} stars wide",
+                year, ROW_WIDTH
+            ));
+        }
+        line.replace_range(start..start + ROW_WIDTH, row);
+    }
+    let mut updated = lines.join("\n");
+    if readme.ends_with('\n') {
+        updated.push('\n');
+    }
+    Ok(updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    const README: &str = "```
+.-----------------------------------------------------.
+|   _   |  Year | Stars                                               |
+|  /_\\  |  2023 | ***************************************.*.........  |
+|       |  2015 | **************....................................  |
+'-----------------------------------------------------'
+```
+
+## Usage
+
+|  2023 | untouched
+";
+
+    #[test]
+    fn rewrites_only_star_rows() {
+        let rows = BTreeMap::from([(2023, "*.".repeat(25))]);
+        let readme = update_readme(README, &rows).unwrap();
+        assert_eq!(
+            readme,
+            README.replace(
+                "***************************************.*.........",
+                &"*.".repeat(25)
+            )
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_rows() {
+        let rows = BTreeMap::from([(2016, ".".repeat(50))]);
+        assert!(update_readme(README, &rows)
+            .unwrap_err()
+            .contains("no row for 2016"));
+
+        let narrow = README.replace("**************......", "**************");
+        let rows = BTreeMap::from([(2015, ".".repeat(50))]);
+        assert!(update_readme(&narrow, &rows)
+            .unwrap_err()
+            .contains("not 50 stars wide"));
+    }
+
+    #[test]
+    fn reads_answers_and_ledger() {
+        let year_dir = tempfile::tempdir().unwrap();
+        assert!(!has_records(year_dir.path()));
+
+        let day_1 = year_dir.path().join("day_1");
+        let day_3 = year_dir.path().join("day_3");
+        fs::create_dir_all(&day_1).unwrap();
+        fs::create_dir_all(&day_3).unwrap();
+        fs::write(day_1.join(answers::FILE_NAME), "part1 = 1\npart2 = 2\n").unwrap();
+        fs::write(
+            day_3.join("ledger.jsonl"),
+            "{\"time\":0,\"part\":1,\"answer\":\"7\",\"verdict\":\"too_low\"}\n\
+             {\"time\":0,\"part\":1,\"answer\":\"9\",\"verdict\":\"correct\"}\n",
+        )
+        .unwrap();
+        assert!(has_records(year_dir.path()));
+
+        let row = row(|day, part| is_solved(&year_dir.path().join(format!("day_{}", day)), part))
+            .unwrap();
+        assert_eq!(row, format!("**..*.{}", ".".repeat(44)));
+    }
+}
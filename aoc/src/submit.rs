@@ -0,0 +1,206 @@
+use std::time::Duration;
+
+use aoc_runner::Part;
+
+use crate::client::Client;
+use crate::html;
+use crate::ledger::{Attempt, Ledger, Verdict};
+
+/// The outcome of a submitted answer, as read from the response page.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Response {
+    pub verdict: Verdict,
+    /// How long the server asked to wait before the next attempt.
+    pub wait: Option<Duration>,
+    /// The text of the response, without markup.
+    pub message: String,
+}
+
+/// Parses durations such as "1m 12s" or "38s".
+fn parse_duration(text: &str) -> Option<Duration> {
+    text.split_whitespace()
+        .try_fold(Duration::ZERO, |total, part| {
+            let unit = part.chars().last()?;
+            let value: u64 = part[..part.len() - 1].parse().ok()?;
+            let seconds = match unit {
+                'h' => value * 3600,
+                'm' => value * 60,
+                's' => value,
+                _ => return None,
+            };
+            Some(total + Duration::from_secs(seconds))
+        })
+}
+
+/// Parses waits such as "please wait one minute" or "please wait 5 minutes".
+fn parse_minutes(text: &str) -> Option<Duration> {
+    let text = text.to_lowercase();
+    let rest = &text[text.find("please wait ")? + "please wait ".len()..];
+    let (count, rest) = rest.split_once(' ')?;
+    if !rest.starts_with("minute") {
+        return None;
+    }
+    let minutes = match count {
+        "one" => 1,
+        count => count.parse().ok()?,
+    };
+    Some(Duration::from_secs(minutes * 60))
+}
+
+pub fn parse_response(html: &str) -> Result<Response, String> {
+    let message = html::elements(html, "article")
+        .first()
+        .map(|article| html::inline_text(article))
+        .ok_or("The response has no message")?;
+    let (verdict, wait) = if message.starts_with("That's the right answer") {
+        (Verdict::Correct, None)
+    } else if message.starts_with("That's not the right answer") {
+        let verdict = if message.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if message.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        };
+        (verdict, parse_minutes(&message))
+    } else if message.starts_with("You gave an answer too recently") {
+        let wait = message
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .and_then(|(duration, _)| parse_duration(duration));
+        (Verdict::TooRecent, wait)
+    } else if message.starts_with("You don't seem to be solving the right level") {
+        (Verdict::AlreadySolved, None)
+    } else {
+        return Err(format!("Unexpected response: {}", message));
+    };
+    Ok(Response {
+        verdict,
+        wait,
+        message,
+    })
+}
+
+/// Submits an answer unless the ledger shows it's pointless, and records the outcome.
+pub fn submit(
+    client: &Client,
+    ledger: &mut Ledger,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> Result<Response, String> {
+    ledger
+        .check(part, answer)
+        .map_err(|refusal| format!("Not submitting {}: {}", answer, refusal))?;
+
+    let level = part.number().to_string();
+    let html = client.post_form(
+        &format!("/{}/day/{}/answer", year, day),
+        &[("level", &level), ("answer", answer)],
+    )?;
+    let response = parse_response(&html)?;
+    ledger.record(Attempt::new(part, answer, response.verdict, response.wait))?;
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::MockServer;
+
+    const CORRECT: &str = include_str!("../testdata/submit/correct.html");
+    const TOO_HIGH: &str = include_str!("../testdata/submit/too_high.html");
+    const TOO_LOW: &str = include_str!("../testdata/submit/too_low.html");
+    const WRONG: &str = include_str!("../testdata/submit/wrong.html");
+    const TOO_RECENT: &str = include_str!("../testdata/submit/too_recent.html");
+    const ALREADY_SOLVED: &str = include_str!("../testdata/submit/already_solved.html");
+
+    #[test]
+    fn parses_recorded_responses() {
+        let minute = Some(Duration::from_secs(60));
+        let cases = [
+            (CORRECT, Verdict::Correct, None),
+            (TOO_HIGH, Verdict::TooHigh, minute),
+            (TOO_LOW, Verdict::TooLow, minute),
+            (WRONG, Verdict::Wrong, Some(Duration::from_secs(300))),
+            (
+                TOO_RECENT,
+                Verdict::TooRecent,
+                Some(Duration::from_secs(72)),
+            ),
+            (ALREADY_SOLVED, Verdict::AlreadySolved, None),
+        ];
+        for (html, verdict, wait) in cases {
+            let response = parse_response(html).unwrap();
+            assert_eq!((response.verdict, response.wait), (verdict, wait));
+        }
+    }
+
+    #[test]
+    fn reads_waits_after_characters_that_grow_when_lowercased() {
+        assert_eq!(
+            parse_minutes("İ Please wait 5 minutes before trying again."),
+            Some(Duration::from_secs(300))
+        );
+        assert_eq!(parse_minutes("Please wait"), None);
+    }
+
+    #[test]
+    fn strips_markup_from_message() {
+        let response = parse_response(CORRECT).unwrap();
+        assert_eq!(
+            response.message,
+            "That's the right answer! You are one gold star closer to restoring snow \
+             operations. [Continue to Part Two]"
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_pages() {
+        assert!(parse_response("<html><body>Hi</body></html>").is_err());
+        assert!(parse_response("<article><p>Something new</p></article>").is_err());
+    }
+
+    fn submit_to(
+        page: &'static str,
+        answers: &[&str],
+    ) -> (MockServer, Vec<Result<Verdict, String>>) {
+        let server = MockServer::start(move |_| (200, page.to_string()));
+        let dir = tempfile::tempdir().unwrap();
+        let client = server.client(dir.path());
+        let mut ledger = Ledger::load(&dir.path().join("ledger.jsonl")).unwrap();
+        let results = answers
+            .iter()
+            .map(|answer| {
+                submit(&client, &mut ledger, 2023, 1, Part::Two, answer)
+                    .map(|response| response.verdict)
+            })
+            .collect();
+        (server, results)
+    }
+
+    #[test]
+    fn posts_answer_and_level() {
+        let (server, results) = submit_to(CORRECT, &["142"]);
+        assert_eq!(results, [Ok(Verdict::Correct)]);
+        let requests = server.requests();
+        assert_eq!(requests[0].url, "/2023/day/1/answer");
+        assert_eq!(requests[0].body, "level=2&answer=142");
+    }
+
+    #[test]
+    fn does_not_resubmit_wrong_answers() {
+        // Answers the server didn't check may be submitted again
+        let (server, results) = submit_to(ALREADY_SOLVED, &["142", "142"]);
+        assert_eq!(results[0], Ok(Verdict::AlreadySolved));
+        assert!(results[1].is_ok());
+        assert_eq!(server.requests().len(), 2);
+
+        let (server, results) = submit_to(TOO_HIGH, &["142", "142", "150"]);
+        assert_eq!(results[0], Ok(Verdict::TooHigh));
+        assert!(results[1].as_ref().unwrap_err().contains("too high"));
+        assert!(results[2].as_ref().unwrap_err().contains("lower than 142"));
+        assert_eq!(server.requests().len(), 1);
+    }
+}
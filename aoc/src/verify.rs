@@ -0,0 +1,297 @@
+//! Checks the days against the answers recorded for their inputs.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use aoc_runner::input::Source;
+use aoc_runner::manifest::Manifest;
+use aoc_runner::{Day, Params, Part};
+
+use crate::answers::Answers;
+use crate::batch::Status;
+
+/// The result of checking a part against its recorded answer.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// No answer was recorded, so the part wasn't solved.
+    Missing,
+}
+
+impl Outcome {
+    fn label(&self) -> &'static str {
+        match self {
+            Outcome::Pass => "pass",
+            Outcome::Fail { .. } => "FAIL",
+            Outcome::Missing => "missing",
+        }
+    }
+}
+
+/// The outcome of each part of a day, or `None` when its input is absent.
+pub type Report = Option<[Outcome; 2]>;
+
+/// Solves the parts of the day in `dir` that have a recorded answer with `solve`, which is given
+/// the input, the part and its parameters, and compares.
+pub fn verify_day(
+    day: &Day,
+    dir: &Path,
+    solve: impl Fn(&str, Part, &Params) -> Status,
+) -> Result<Report, String> {
+    let path = dir.join("input");
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = day.load(&Source::File(path), dir)?;
+    let answers = Answers::load(dir)?;
+    let manifest = Manifest::load(dir)?;
+
+    let mut outcomes = [Outcome::Missing, Outcome::Missing];
+    for (outcome, part) in outcomes.iter_mut().zip(Part::ALL) {
+        let Some(expected) = answers.get(part) else {
+            continue;
+        };
+        let params = manifest.params(day, part)?;
+        *outcome = match solve(&content, part, &params) {
+            Status::Solved(actual) if actual == expected => Outcome::Pass,
+            status => Outcome::Fail {
+                expected: expected.to_string(),
+                actual: match status {
+                    Status::Solved(actual) => actual,
+                    status => status.detail().unwrap_or_default(),
+                },
+            },
+        };
+    }
+    Ok(Some(outcomes))
+}
+
+/// Renders a matrix of the outcomes per year, followed by the details of the failures.
+pub fn render(reports: &[(&Day, Report)]) -> String {
+    let mut matrix = String::new();
+    let mut failures = String::new();
+    let mut year = None;
+    for (day, report) in reports {
+        if year != Some(day.year) {
+            if year.is_some() {
+                matrix.push('\n');
+            }
+            year = Some(day.year);
+            writeln!(matrix, "{:<8}{:<9}part 2", day.year, "part 1").unwrap();
+        }
+        let label = format!("day {}", day.day);
+        let Some(outcomes) = report else {
+            writeln!(matrix, "{:<8}no input", label).unwrap();
+            continue;
+        };
+        writeln!(
+            matrix,
+            "{:<8}{:<9}{}",
+            label,
+            outcomes[0].label(),
+            outcomes[1].label()
+        )
+        .unwrap();
+        for (outcome, part) in outcomes.iter().zip(Part::ALL) {
+            if let Outcome::Fail { expected, actual } = outcome {
+                writeln!(
+                    failures,
+                    "{} day {} part {}: expected {}, got {}",
+                    day.year, day.day, part, expected, actual
+                )
+                .unwrap();
+            }
+        }
+    }
+    if !failures.is_empty() {
+        matrix.push('\n');
+        matrix.push_str(&failures);
+    }
+    matrix
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::batch::panic_message;
+    use aoc_runner::parse::ParseError;
+    use aoc_runner::Solution;
+    use std::fs;
+    use std::panic::{self, AssertUnwindSafe};
+
+    struct Length;
+
+    impl Solution for Length {
+        const YEAR: u16 = 2023;
+        const DAY: u8 = 1;
+
+        type Input = String;
+        type Answer1 = usize;
+        type Answer2 = usize;
+
+        fn parse(input: &str) -> Result<String, ParseError> {
+            match input.find('!') {
+                Some(i) => Err(ParseError::at(input, &input[i..i + 1], "a letter")),
+                None => Ok(input.trim_end().to_string()),
+            }
+        }
+
+        fn part1(input: &String, _params: &Params) -> usize {
+            input.len()
+        }
+
+        fn part2(input: &String, _params: &Params) -> usize {
+            assert!(!input.is_empty(), "Empty input");
+            input.len() * 2
+        }
+    }
+
+    /// Solves a part in this process, as the child process of a batch would.
+    fn solve(input: &str, part: Part, params: &Params) -> Status {
+        let day = Day::of::<Length>();
+        let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+            let parsed = day.parse(input)?;
+            Ok::<_, ParseError>(day.solve_part(parsed.as_ref(), part, params))
+        }));
+        match solved {
+            Ok(Ok(answer)) => Status::Solved(answer),
+            Ok(Err(e)) => Status::Failed(format!("invalid input at {}", e)),
+            Err(payload) => Status::Panicked(panic_message(payload)),
+        }
+    }
+
+    fn verify_with(
+        input: Option<&str>,
+        answers: &str,
+        solve: impl Fn(&str, Part, &Params) -> Status,
+    ) -> Report {
+        let dir = tempfile::tempdir().unwrap();
+        if let Some(input) = input {
+            fs::write(dir.path().join("input"), input).unwrap();
+        }
+        fs::write(dir.path().join("answers.toml"), answers).unwrap();
+        verify_day(&Day::of::<Length>(), dir.path(), solve).unwrap()
+    }
+
+    fn verify(input: Option<&str>, answers: &str) -> Report {
+        verify_with(input, answers, solve)
+    }
+
+    #[test]
+    fn compares_recorded_answers() {
+        assert_eq!(
+            verify(Some("abc"), "part1 = 3\npart2 = 7\n"),
+            Some([
+                Outcome::Pass,
+                Outcome::Fail {
+                    expected: "7".to_string(),
+                    actual: "6".to_string()
+                }
+            ])
+        );
+        assert_eq!(
+            verify(Some("abc"), "part1 = 3\n"),
+            Some([Outcome::Pass, Outcome::Missing])
+        );
+    }
+
+    #[test]
+    fn skips_absent_inputs() {
+        assert_eq!(verify(None, "part1 = 3\n"), None);
+    }
+
+    #[test]
+    fn reports_panics_as_failures() {
+        assert_eq!(
+            verify(Some(""), "part2 = 0\n"),
+            Some([
+                Outcome::Missing,
+                Outcome::Fail {
+                    expected: "0".to_string(),
+                    actual: "panicked: Empty input".to_string()
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn reports_invalid_inputs_as_failures() {
+        let actual = "invalid input at line 1, column 3: expected a letter, found \"!\"";
+        assert_eq!(
+            verify(Some("ab!"), "part1 = 3\n"),
+            Some([
+                Outcome::Fail {
+                    expected: "3".to_string(),
+                    actual: actual.to_string()
+                },
+                Outcome::Missing
+            ])
+        );
+        assert_eq!(
+            verify(Some("ab!"), "part1 = 3\npart2 = 6\n"),
+            Some([
+                Outcome::Fail {
+                    expected: "3".to_string(),
+                    actual: actual.to_string()
+                },
+                Outcome::Fail {
+                    expected: "6".to_string(),
+                    actual: actual.to_string()
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn reports_parts_beyond_the_limits_as_failures() {
+        let limited = |_: &str, part, _: &Params| match part {
+            Part::One => Status::TimedOut,
+            Part::Two => Status::OutOfMemory,
+        };
+        assert_eq!(
+            verify_with(Some("abc"), "part1 = 3\npart2 = 6\n", limited),
+            Some([
+                Outcome::Fail {
+                    expected: "3".to_string(),
+                    actual: "timed out".to_string()
+                },
+                Outcome::Fail {
+                    expected: "6".to_string(),
+                    actual: "ran out of memory".to_string()
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn renders_matrix_per_year() {
+        let day = Day::of::<Length>();
+        let reports = [
+            (&day, Some([Outcome::Pass, Outcome::Missing])),
+            (&day, None),
+            (
+                &day,
+                Some([
+                    Outcome::Pass,
+                    Outcome::Fail {
+                        expected: "7".to_string(),
+                        actual: "6".to_string(),
+                    },
+                ]),
+            ),
+        ];
+        assert_eq!(
+            render(&reports),
+            "2023    part 1   part 2\n\
+             day 1   pass     missing\n\
+             day 1   no input\n\
+             day 1   pass     FAIL\n\
+             \n\
+             2023 day 1 part 2: expected 7, got 6\n"
+        );
+    }
+}
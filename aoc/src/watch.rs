@@ -0,0 +1,236 @@
+//! Re-runs the tests and the solution of a day whenever its sources or inputs change.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use aoc_runner::{day_dir, Part};
+
+use crate::batch::Limits;
+
+/// How often to look for changes.
+const INTERVAL: Duration = Duration::from_millis(500);
+
+/// The modification times of the watched files of a day.
+pub type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+/// The answers of the latest run of a day, per part.
+pub type Answers = [Option<String>; 2];
+
+/// Adds the files in `dir` and its subdirectories to `snapshot`, skipping build output.
+fn add_tree(snapshot: &mut Snapshot, dir: &Path) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            if path.file_name().is_some_and(|name| name != "target") {
+                add_tree(snapshot, &path);
+            }
+        } else if let Ok(modified) = entry.metadata().and_then(|m| m.modified()) {
+            snapshot.insert(path, modified);
+        }
+    }
+}
+
+/// Returns the modification times of the sources, manifest and inputs of the day in `dir`.
+pub fn snapshot(dir: &Path) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    add_tree(&mut snapshot, &dir.join("solution"));
+    let Ok(entries) = fs::read_dir(dir) else {
+        return snapshot;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if name == "input" || name == "day.toml" || name.starts_with("input-example-") {
+            if let Ok(modified) = entry.metadata().and_then(|m| m.modified()) {
+                snapshot.insert(entry.path(), modified);
+            }
+        }
+    }
+    snapshot
+}
+
+/// Returns the files that were added, changed or removed between two snapshots.
+pub fn changes<'a>(before: &'a Snapshot, after: &'a Snapshot) -> Vec<&'a Path> {
+    let changed = after
+        .iter()
+        .filter(|(path, modified)| before.get(*path) != Some(modified))
+        .map(|(path, _)| path.as_path());
+    let removed = before
+        .keys()
+        .filter(|path| !after.contains_key(*path))
+        .map(PathBuf::as_path);
+    changed.chain(removed).collect()
+}
+
+/// Reads the answers from the output of a day's binary, such as `Solution part 1: 42`.
+pub fn parse_answers(output: &str) -> Answers {
+    let mut answers = Answers::default();
+    for line in output.lines() {
+        let Some(rest) = line.strip_prefix("Solution part ") else {
+            continue;
+        };
+        let Some((part, answer)) = rest.split_once(": ") else {
+            continue;
+        };
+        if let Some(part) = part.parse().ok().and_then(Part::from_number) {
+            answers[part.number() as usize - 1] = Some(answer.to_string());
+        }
+    }
+    answers
+}
+
+/// Describes the answers of a run compared with those of the run before.
+pub fn diff(previous: Option<&Answers>, current: &Answers) -> String {
+    let mut lines = vec![];
+    for part in Part::ALL {
+        let index = part.number() as usize - 1;
+        let before = previous.and_then(|answers| answers[index].as_deref());
+        let line = match (before, current[index].as_deref()) {
+            (_, None) => format!("part {}: no answer", part),
+            (None, Some(answer)) if previous.is_none() => format!("part {}: {}", part, answer),
+            (None, Some(answer)) => format!("part {}: {} (new)", part, answer),
+            (Some(before), Some(answer)) if before == answer => {
+                format!("part {}: {} (unchanged)", part, answer)
+            }
+            (Some(before), Some(answer)) => format!("part {}: {} (was {})", part, answer, before),
+        };
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+fn cargo(root: &Path, args: &[&str]) -> Command {
+    let mut command = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()));
+    command.current_dir(root).args(args);
+    command
+}
+
+/// Runs the tests of a day, then solves its input if it has one within `limits`, and returns the
+/// answers.
+fn run_once(root: &Path, year: u16, day: u8, limits: Limits) -> Result<Option<Answers>, String> {
+    let package = format!("aoc-{}-day-{}", year, day);
+    let status = cargo(root, &["test", "-q", "-p", &package])
+        .status()
+        .map_err(|e| format!("Could not run cargo: {}", e))?;
+    println!(
+        "Tests {}",
+        if status.success() { "passed" } else { "failed" }
+    );
+
+    if !day_dir(year, day).join("input").exists() {
+        println!("No input to solve (try `aoc fetch {} {}`)", year, day);
+        return Ok(None);
+    }
+    // Solved through `aoc run`, which kills a part that runs away instead of blocking the watch
+    let output = cargo(root, &["run", "-q", "--release", "-p", "aoc", "--", "run"])
+        .args([
+            year.to_string(),
+            day.to_string(),
+            format!("--timeout={}", limits.timeout.as_secs()),
+            format!("--memory={}", limits.memory.map_or(0, |bytes| bytes >> 20)),
+        ])
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|e| format!("Could not run cargo: {}", e))?;
+    // A part that failed has no answer, but the other may still have one
+    if !output.status.success() {
+        println!("Solving the input failed");
+    }
+    Ok(Some(parse_answers(&String::from_utf8_lossy(
+        &output.stdout,
+    ))))
+}
+
+/// Watches the day and re-runs it on every change, until interrupted.
+pub fn watch(root: &Path, year: u16, day: u8, limits: Limits) -> Result<(), String> {
+    let dir = day_dir(year, day);
+    if !dir.is_dir() {
+        return Err(format!("{} does not exist", dir.display()));
+    }
+    println!("Watching {}", dir.display());
+    let mut previous: Option<Answers> = None;
+    let mut before = snapshot(&dir);
+    loop {
+        if let Some(answers) = run_once(root, year, day, limits)? {
+            println!("{}", diff(previous.as_ref(), &answers));
+            previous = Some(answers);
+        }
+        loop {
+            thread::sleep(INTERVAL);
+            let after = snapshot(&dir);
+            let changed: Vec<String> = changes(&before, &after)
+                .iter()
+                .map(|path| {
+                    path.strip_prefix(&dir)
+                        .unwrap_or(path)
+                        .display()
+                        .to_string()
+                })
+                .collect();
+            if !changed.is_empty() {
+                println!("\nChanged: {}", changed.join(", "));
+                before = after;
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_changed_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("solution/src");
+        fs::create_dir_all(&src).unwrap();
+        fs::create_dir_all(dir.path().join("solution/target")).unwrap();
+        fs::write(src.join("lib.rs"), "").unwrap();
+        fs::write(dir.path().join("solution/target/out"), "").unwrap();
+        fs::write(dir.path().join("input-example-1"), "").unwrap();
+        fs::write(dir.path().join("notes.md"), "").unwrap();
+        let before = snapshot(dir.path());
+        assert_eq!(before.len(), 2);
+
+        let time = |secs| SystemTime::UNIX_EPOCH + Duration::from_secs(secs);
+        let mut after = before.clone();
+        after.insert(src.join("lib.rs"), time(1));
+        after.insert(dir.path().join("input"), time(2));
+        after.remove(&dir.path().join("input-example-1"));
+        assert_eq!(
+            changes(&before, &after),
+            [
+                dir.path().join("input").as_path(),
+                src.join("lib.rs").as_path(),
+                dir.path().join("input-example-1").as_path(),
+            ]
+        );
+        assert!(changes(&after, &after).is_empty());
+    }
+
+    #[test]
+    fn compares_answers_with_previous_run() {
+        let first = parse_answers("Solution part 1: 8\nSolution part 2: 2286\n");
+        assert_eq!(first, [Some("8".to_string()), Some("2286".to_string())]);
+        assert_eq!(diff(None, &first), "part 1: 8\npart 2: 2286");
+        let second = parse_answers("Warning\nSolution part 1: 8\nSolution part 2: 2287\n");
+        assert_eq!(
+            diff(Some(&first), &second),
+            "part 1: 8 (unchanged)\npart 2: 2287 (was 2286)"
+        );
+        let partial = parse_answers("Solution part 1: 8\n");
+        assert_eq!(
+            diff(Some(&partial), &second),
+            "part 1: 8 (unchanged)\npart 2: 2287 (new)"
+        );
+    }
+}
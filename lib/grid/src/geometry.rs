@@ -0,0 +1,258 @@
+//! Points on the plane and the directions between them. As in the rows of a grid, `y` grows
+//! downwards, so north is `(0, -1)`.
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::Position;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point<T = i64> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> Point<T> {
+    /// Returns the number of orthogonal steps between two points.
+    pub fn manhattan(self, other: Self) -> T {
+        let distance = |a: T, b: T| if a > b { a - b } else { b - a };
+        distance(self.x, other.x) + distance(self.y, other.y)
+    }
+}
+
+impl Point {
+    /// Returns the point one step away in a direction.
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.delta()
+    }
+
+    /// Returns the four orthogonal neighbors, clockwise from the north.
+    pub fn neighbors4(self) -> [Self; 4] {
+        Direction::ALL.map(|direction| self + direction.delta())
+    }
+
+    /// Returns all eight neighbors, clockwise from the north.
+    pub fn neighbors8(self) -> [Self; 8] {
+        Direction8::ALL.map(|direction| self + direction.delta())
+    }
+
+    /// Returns the position of the point in a grid, unless it lies left of or above the grid.
+    pub fn position(self) -> Option<Position> {
+        Some((self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+}
+
+impl From<Position> for Point {
+    fn from((x, y): Position) -> Self {
+        Self::new(x as i64, y as i64)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+/// Scales a point, such as a delta by the number of steps taken.
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Self::new(self.x * factor, self.y * factor)
+    }
+}
+
+/// One of the four orthogonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// All directions, clockwise from the north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Parses a direction such as `U`, `N` or `^`, as used by puzzles for up, north or an arrow.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'U' | 'N' | '^' => Some(Direction::North),
+            'R' | 'E' | '>' => Some(Direction::East),
+            'D' | 'S' | 'v' => Some(Direction::South),
+            'L' | 'W' | '<' => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    /// Returns the offset of a step in this direction.
+    pub fn delta(self) -> Point {
+        match self {
+            Direction::North => Point::new(0, -1),
+            Direction::East => Point::new(1, 0),
+            Direction::South => Point::new(0, 1),
+            Direction::West => Point::new(-1, 0),
+        }
+    }
+
+    /// Returns the direction a quarter turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    /// Returns the direction a quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+/// One of the four orthogonal and four diagonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// All directions, clockwise from the north.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// Returns the offset of a step in this direction, which is diagonal for the diagonal
+    /// directions.
+    pub fn delta(self) -> Point {
+        match self {
+            Direction8::North => Point::new(0, -1),
+            Direction8::NorthEast => Point::new(1, -1),
+            Direction8::East => Point::new(1, 0),
+            Direction8::SouthEast => Point::new(1, 1),
+            Direction8::South => Point::new(0, 1),
+            Direction8::SouthWest => Point::new(-1, 1),
+            Direction8::West => Point::new(-1, 0),
+            Direction8::NorthWest => Point::new(-1, -1),
+        }
+    }
+
+    /// Returns the direction an eighth of a turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    /// Returns the direction an eighth of a turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        Self::ALL[direction as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns_around() {
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+        assert_eq!(Direction::East.opposite(), Direction::West);
+        assert_eq!(Direction8::North.turn_left(), Direction8::NorthWest);
+        assert_eq!(Direction8::SouthWest.opposite(), Direction8::NorthEast);
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_left(), direction.opposite());
+            assert_eq!(direction.delta(), Direction8::from(direction).delta());
+            assert_eq!(direction.opposite().delta(), -direction.delta());
+        }
+    }
+
+    #[test]
+    fn parses_letters_and_arrows() {
+        let parse = |text: &str| text.chars().map(Direction::from_char).collect::<Vec<_>>();
+        assert_eq!(parse("URDL"), parse("NESW"));
+        assert_eq!(parse("^>v<"), parse("NESW"));
+        assert_eq!(parse("NESW"), Direction::ALL.map(Some));
+        assert_eq!(Direction::from_char('x'), None);
+    }
+
+    #[test]
+    fn measures_and_moves_points() {
+        let point = Point::new(2, -3);
+        assert_eq!(point.manhattan(Point::new(-1, 1)), 7);
+        assert_eq!(Point::<u32>::new(1, 5).manhattan(Point::new(4, 2)), 6);
+        assert_eq!(point.step(Direction::North), Point::new(2, -4));
+        assert_eq!(point + Direction::East.delta() * 4, Point::new(6, -3));
+        assert_eq!(point.neighbors4()[1], Point::new(3, -3));
+        assert_eq!(point.neighbors8()[7], Point::new(1, -4));
+        assert_eq!(point.position(), None);
+        assert_eq!(Point::from((3, 4)).position(), Some((3, 4)));
+    }
+}
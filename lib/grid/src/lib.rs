@@ -0,0 +1,347 @@
+//! A rectangular grid of cells, such as the maps of many puzzles, stored row by row.
+
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+use std::slice;
+
+use aoc_runner::parse::ParseError;
+
+use crate::geometry::Direction;
+
+pub mod geometry;
+
+/// The column and the row of a cell, counting from the top left.
+pub type Position = (usize, usize);
+
+/// The offsets of the four orthogonal neighbors of a cell, clockwise from the one above it.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The offsets of all eight neighbors of a cell, clockwise from the one above it.
+pub const ALL_AROUND: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid with the cell returned by `cell` for each position.
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Position) -> T) -> Self {
+        let cells = (0..width * height)
+            .map(|i| cell((i % width, i / width)))
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Creates a grid with `value` in every cell.
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Parses a grid with a line per row and a character per cell, which `cell` converts. It
+    /// returns `None` for characters that aren't what is `expected`, such as "# or .". Every row
+    /// should be as long as the first.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, ParseError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+        for line in input.lines() {
+            let mut columns = 0;
+            for (i, c) in line.char_indices() {
+                if let Some(width) = width.filter(|width| columns == *width) {
+                    let rest = &line[i..];
+                    return Err(ParseError::at(
+                        input,
+                        rest,
+                        format!("a row of {} cells", width),
+                    ));
+                }
+                let text = &line[i..i + c.len_utf8()];
+                cells.push(cell(c).ok_or_else(|| ParseError::at(input, text, expected))?);
+                columns += 1;
+            }
+            if let Some(width) = width.filter(|width| columns < *width) {
+                let end = &line[line.len()..];
+                return Err(ParseError::at(
+                    input,
+                    end,
+                    format!("a row of {} cells", width),
+                ));
+            }
+            width = Some(columns);
+            height += 1;
+        }
+        let width = width.ok_or_else(|| ParseError::at(input, input, "a row of cells"))?;
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns whether a position lies within the grid.
+    pub fn contains(&self, (x, y): Position) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        let (x, y) = position;
+        self.contains(position)
+            .then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        let (x, y) = position;
+        self.contains(position)
+            .then(|| &mut self.cells[y * self.width + x])
+    }
+
+    /// Returns the cell at a position in the grid repeated infinitely in every direction, such
+    /// as `(-1, 0)` for the last cell of the first row.
+    pub fn get_wrapping(&self, (x, y): (isize, isize)) -> &T {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+        &self.cells[y * self.width + x]
+    }
+
+    /// Returns the position `delta` away from `position`, unless that lies outside the grid.
+    pub fn offset(&self, (x, y): Position, (dx, dy): (isize, isize)) -> Option<Position> {
+        let position = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(position).then_some(position)
+    }
+
+    /// Returns the position one step away in a direction, unless that lies outside the grid.
+    pub fn step(&self, position: Position, direction: Direction) -> Option<Position> {
+        let delta = direction.delta();
+        self.offset(position, (delta.x as isize, delta.y as isize))
+    }
+
+    /// Returns the positions of the orthogonal neighbors of a cell that lie within the grid.
+    pub fn neighbors4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |delta| self.offset(position, delta))
+    }
+
+    /// Returns the positions of all neighbors of a cell, including diagonal ones, that lie
+    /// within the grid.
+    pub fn neighbors8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        ALL_AROUND
+            .into_iter()
+            .filter_map(move |delta| self.offset(position, delta))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// Returns the cells of a column from top to bottom.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        assert!(x < self.width, "Column {} is outside the grid", x);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Returns every position row by row, from the top left.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.width * self.height).map(move |i| (i % width, i / width))
+    }
+
+    /// Returns every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> + '_ {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Returns every cell, row by row.
+    pub fn values(&self) -> slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    /// Returns the position of the first cell, row by row, that matches `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        let i = self.cells.iter().position(predicate)?;
+        Some((i % self.width, i / self.width))
+    }
+
+    /// Returns a grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        let (width, height) = (self.width, self.height);
+        self.get(position)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid of {}x{}", position, width, height))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid of {}x{}", position, width, height))
+    }
+}
+
+/// Renders a line per row, which is how grids are parsed when every cell renders as the
+/// character it was parsed from.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "#..\n.#.\n..#\n#.#\n";
+
+    fn grid() -> Grid<char> {
+        Grid::parse(INPUT, |c| matches!(c, '#' | '.').then_some(c), "# or .").unwrap()
+    }
+
+    #[test]
+    fn parses_and_renders_rows() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+        assert_eq!(grid[(1, 1)], '#');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), INPUT.trim_end());
+    }
+
+    #[test]
+    fn points_at_invalid_cells_and_rows() {
+        let parse = |input| Grid::parse(input, |c| (c == '.').then_some(c), ".").unwrap_err();
+        let error = parse("...\n.x.\n");
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected ., found \"x\""
+        );
+        let error = parse("...\n.\n");
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected a row of 3 cells, found nothing"
+        );
+        let error = parse("..\n....\n");
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected a row of 2 cells, found \"..\""
+        );
+        let error = parse("");
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected a row of cells, found nothing"
+        );
+    }
+
+    #[test]
+    fn finds_neighbors_within_bounds() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbors8((2, 3)).collect::<Vec<_>>(),
+            [(2, 2), (1, 3), (1, 2)]
+        );
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+        assert_eq!(grid.offset((2, 1), (0, 2)), Some((2, 3)));
+        assert_eq!(grid.offset((2, 1), (1, 0)), None);
+        assert_eq!(grid.step((2, 1), Direction::West), Some((1, 1)));
+        assert_eq!(grid.step((2, 1), Direction::East), None);
+    }
+
+    #[test]
+    fn views_rows_and_columns() {
+        let grid = grid();
+        assert_eq!(grid.row(3), ['#', '.', '#']);
+        let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+        assert_eq!(columns, ["#..#", ".#..", "..##"]);
+        assert_eq!(grid.position(|c| *c == '#'), Some((0, 0)));
+        assert_eq!(
+            grid.iter().filter(|(_, c)| **c == '#').count(),
+            grid.values().filter(|c| **c == '#').count()
+        );
+    }
+
+    #[test]
+    fn wraps_around_edges() {
+        let grid = grid();
+        assert_eq!(*grid.get_wrapping((-1, 0)), '.');
+        assert_eq!(*grid.get_wrapping((-1, -1)), '#');
+        assert_eq!(*grid.get_wrapping((4, 5)), '#');
+    }
+
+    #[test]
+    fn builds_from_positions() {
+        let mut grid = Grid::from_fn(3, 2, |(x, y)| x + 10 * y);
+        assert_eq!(grid.row(1), [10, 11, 12]);
+        grid[(0, 1)] = 0;
+        assert_eq!(grid.map(|n| n % 2).to_string(), "010\n010");
+        assert_eq!(Grid::filled(2, 2, 7).values().sum::<i32>(), 28);
+    }
+}
@@ -0,0 +1,78 @@
+//! Boxes of integer points in any number of dimensions, which split along one axis at a time.
+
+use crate::Interval;
+
+/// The points whose coordinate along every axis lies within the interval for that axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid<const N: usize> {
+    pub axes: [Interval; N],
+}
+
+impl<const N: usize> Cuboid<N> {
+    pub const fn new(axes: [Interval; N]) -> Self {
+        Self { axes }
+    }
+
+    /// Creates the box of a single point.
+    pub fn point(coordinates: [i64; N]) -> Self {
+        Self::new(coordinates.map(|coordinate| Interval::with_len(coordinate, 1)))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(|interval| interval.is_empty())
+    }
+
+    /// Returns the number of points in the box.
+    pub fn volume(&self) -> u64 {
+        self.axes.iter().map(|interval| interval.len()).product()
+    }
+
+    pub fn contains(&self, point: [i64; N]) -> bool {
+        self.axes
+            .iter()
+            .zip(point)
+            .all(|(interval, coordinate)| interval.contains(coordinate))
+    }
+
+    /// Returns the points in both boxes, unless there are none.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let mut axes = self.axes;
+        for (interval, other) in axes.iter_mut().zip(other.axes) {
+            *interval = interval.intersection(other)?;
+        }
+        Some(Self::new(axes))
+    }
+
+    /// Splits the box into the points whose coordinate along `axis` is below `at` and the points
+    /// where it is `at` or more, leaving out either half if it would be empty.
+    pub fn split_at(&self, axis: usize, at: i64) -> (Option<Self>, Option<Self>) {
+        let (low, high) = self.axes[axis].split_at(at);
+        let with = |interval| {
+            let mut axes = self.axes;
+            axes[axis] = interval;
+            Self::new(axes)
+        };
+        (low.map(with), high.map(with))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_along_an_axis() {
+        let cuboid = Cuboid::new([Interval::from(1..=10), Interval::from(1..=4)]);
+        assert_eq!(cuboid.volume(), 40);
+        let (low, high) = cuboid.split_at(1, 2);
+        let (low, high) = (low.unwrap(), high.unwrap());
+        assert_eq!((low.volume(), high.volume()), (10, 30));
+        assert!(low.contains([10, 1]) && !low.contains([10, 2]));
+        assert_eq!(low.intersection(&high), None);
+        assert_eq!(cuboid.split_at(0, 20), (Some(cuboid), None));
+        assert_eq!(
+            cuboid.intersection(&Cuboid::point([3, 4])),
+            Some(Cuboid::point([3, 4]))
+        );
+    }
+}
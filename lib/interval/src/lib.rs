@@ -0,0 +1,263 @@
+//! Intervals of integers and sets of them, for puzzles that would take too long one integer at a
+//! time.
+
+use std::ops::{Range, RangeInclusive};
+use std::slice;
+
+pub mod cuboid;
+pub mod map;
+
+/// The integers from `start` up to but not including `end`, which is empty unless `start < end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub const fn new(start: i64, end: i64) -> Self {
+        Self { start, end }
+    }
+
+    /// Creates the interval of `len` integers from `start`.
+    pub const fn with_len(start: i64, len: i64) -> Self {
+        Self::new(start, start + len)
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.start >= self.end
+    }
+
+    /// Returns the number of integers in the interval.
+    pub fn len(self) -> u64 {
+        if self.is_empty() {
+            0
+        } else {
+            self.end.abs_diff(self.start)
+        }
+    }
+
+    pub fn contains(self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Returns the integers in both intervals, unless there are none.
+    pub fn intersection(self, other: Self) -> Option<Self> {
+        let interval = Self::new(self.start.max(other.start), self.end.min(other.end));
+        (!interval.is_empty()).then_some(interval)
+    }
+
+    /// Returns the interval with `offset` added to both ends.
+    pub fn shift(self, offset: i64) -> Self {
+        Self::new(self.start + offset, self.end + offset)
+    }
+
+    /// Splits the interval into the integers below `at` and the integers from `at` on, leaving
+    /// out either half if it would be empty.
+    pub fn split_at(self, at: i64) -> (Option<Self>, Option<Self>) {
+        let low = Self::new(self.start, self.end.min(at));
+        let high = Self::new(self.start.max(at), self.end);
+        (
+            (!low.is_empty()).then_some(low),
+            (!high.is_empty()).then_some(high),
+        )
+    }
+}
+
+impl From<Range<i64>> for Interval {
+    fn from(range: Range<i64>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
+
+impl From<RangeInclusive<i64>> for Interval {
+    fn from(range: RangeInclusive<i64>) -> Self {
+        Self::new(*range.start(), range.end() + 1)
+    }
+}
+
+/// A set of integers, stored as the fewest intervals that cover it in ascending order. Intervals
+/// that overlap or touch are coalesced as they are added.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the integers of an interval to the set.
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+        // The intervals from `first` up to `last` overlap or touch the new one
+        let first = self.intervals.partition_point(|i| i.end < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end);
+        let mut merged = interval;
+        if first < last {
+            merged.start = merged.start.min(self.intervals[first].start);
+            merged.end = merged.end.max(self.intervals[last - 1].end);
+        }
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    /// Returns the intervals of the set in ascending order.
+    pub fn iter(&self) -> slice::Iter<'_, Interval> {
+        self.intervals.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Returns the number of integers in the set.
+    pub fn len(&self) -> u64 {
+        self.intervals.iter().map(|interval| interval.len()).sum()
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let i = self
+            .intervals
+            .partition_point(|interval| interval.end <= value);
+        self.intervals
+            .get(i)
+            .is_some_and(|interval| interval.contains(value))
+    }
+
+    /// Returns the lowest integer in the set.
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    /// Returns the highest integer in the set.
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().map(|interval| interval.end - 1)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.extend(other.iter().copied());
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            intervals.extend(a.intersection(*b));
+            // Whichever interval ends first can't overlap anything further in the other set
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { intervals }
+    }
+
+    /// Returns the integers of this set that aren't in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        for interval in &self.intervals {
+            let mut start = interval.start;
+            let first = other.intervals.partition_point(|i| i.end <= interval.start);
+            for removed in other.intervals[first..]
+                .iter()
+                .take_while(|i| i.start < interval.end)
+            {
+                if start < removed.start {
+                    intervals.push(Interval::new(start, removed.start));
+                }
+                start = removed.end;
+            }
+            if start < interval.end {
+                intervals.push(Interval::new(start, interval.end));
+            }
+        }
+        Self { intervals }
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> Self {
+        Self::from_iter([interval])
+    }
+}
+
+impl Extend<Interval> for IntervalSet {
+    fn extend<I: IntoIterator<Item = Interval>>(&mut self, intervals: I) {
+        for interval in intervals {
+            self.insert(interval);
+        }
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(intervals: I) -> Self {
+        let mut set = Self::new();
+        set.extend(intervals);
+        set
+    }
+}
+
+impl<'a> IntoIterator for &'a IntervalSet {
+    type Item = &'a Interval;
+    type IntoIter = slice::Iter<'a, Interval>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[Range<i64>]) -> IntervalSet {
+        intervals.iter().cloned().map(Interval::from).collect()
+    }
+
+    #[test]
+    fn splits_intervals() {
+        let interval = Interval::from(1..=4000);
+        assert_eq!(interval.len(), 4000);
+        assert_eq!(
+            interval.split_at(1000),
+            (
+                Some(Interval::new(1, 1000)),
+                Some(Interval::new(1000, 4001))
+            )
+        );
+        assert_eq!(interval.split_at(1), (None, Some(interval)));
+        assert_eq!(interval.split_at(5000), (Some(interval), None));
+        assert_eq!(interval.intersection(Interval::new(4001, 4005)), None);
+        assert_eq!(Interval::new(3, 1).len(), 0);
+    }
+
+    #[test]
+    fn coalesces_overlapping_and_touching_intervals() {
+        let set = set(&[10..20, 0..5, 5..7, 15..25, 30..30, 40..50, 8..9]);
+        assert_eq!(set, self::set(&[0..7, 8..9, 10..25, 40..50]));
+        assert_eq!(set.len(), 33);
+        assert!(set.contains(24) && !set.contains(25) && !set.contains(7));
+        assert_eq!((set.min(), set.max()), (Some(0), Some(49)));
+
+        let mut set = set;
+        set.insert(Interval::new(6, 45));
+        assert_eq!(set, IntervalSet::from(Interval::new(0, 50)));
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a = set(&[0..10, 20..30]);
+        let b = set(&[5..25, 28..40]);
+        assert_eq!(a.union(&b), IntervalSet::from(Interval::new(0, 40)));
+        assert_eq!(a.intersection(&b), set(&[5..10, 20..25, 28..30]));
+        assert_eq!(a.difference(&b), set(&[0..5, 25..28]));
+        assert_eq!(b.difference(&a), set(&[10..20, 30..40]));
+        assert!(a.difference(&a).is_empty());
+    }
+}
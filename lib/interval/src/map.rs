@@ -0,0 +1,184 @@
+//! Maps from integers to integers that add a constant offset within each of their intervals, such
+//! as the almanac maps of 2023 day 5.
+
+use crate::{Interval, IntervalSet};
+
+/// Adds an offset to the integers within each of its intervals, and leaves every other integer
+/// as it is. The intervals are kept in ascending order, with touching intervals that share an
+/// offset coalesced.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct OffsetMap {
+    pieces: Vec<(Interval, i64)>,
+}
+
+impl OffsetMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `offset` to the integers of `interval` that the map has no interval for yet, so that
+    /// where intervals overlap, the one inserted first takes precedence.
+    pub fn insert(&mut self, interval: Interval, offset: i64) {
+        let uncovered = IntervalSet::from(interval).difference(&self.sources());
+        self.pieces
+            .extend(uncovered.iter().map(|interval| (*interval, offset)));
+        self.pieces.sort_unstable();
+        self.pieces
+            .dedup_by(|(next, next_offset), (interval, offset)| {
+                let touching = interval.end == next.start && offset == next_offset;
+                if touching {
+                    interval.end = next.end;
+                }
+                touching
+            });
+    }
+
+    /// Returns the intervals with their offsets, in ascending order.
+    pub fn pieces(&self) -> &[(Interval, i64)] {
+        &self.pieces
+    }
+
+    /// Returns the integers that the map has an interval for.
+    pub fn sources(&self) -> IntervalSet {
+        self.pieces.iter().map(|(interval, _)| *interval).collect()
+    }
+
+    pub fn apply(&self, value: i64) -> i64 {
+        let i = self
+            .pieces
+            .partition_point(|(interval, _)| interval.end <= value);
+        match self.pieces.get(i) {
+            Some((interval, offset)) if interval.contains(value) => value + offset,
+            _ => value,
+        }
+    }
+
+    /// Returns where every integer of the set maps to.
+    pub fn apply_to_set(&self, set: &IntervalSet) -> IntervalSet {
+        set.iter()
+            .flat_map(|interval| self.segments(*interval))
+            .map(|(interval, offset)| interval.shift(offset))
+            .collect()
+    }
+
+    /// Returns the map that applies this map and then `next`.
+    pub fn then(&self, next: &OffsetMap) -> OffsetMap {
+        let mut composed = OffsetMap::new();
+        // The integers this map moves are moved again from where they end up
+        for (interval, offset) in &self.pieces {
+            for (moved, next_offset) in next.segments(interval.shift(*offset)) {
+                composed.insert(moved.shift(-offset), offset + next_offset);
+            }
+        }
+        // The others are only moved by `next`, which is what is left uncovered
+        for (interval, offset) in &next.pieces {
+            composed.insert(*interval, *offset);
+        }
+        composed
+    }
+
+    /// Returns the map that undoes this one, unless some integers map to the same integer.
+    pub fn inverse(&self) -> Option<OffsetMap> {
+        // Integers outside the intervals stay put, so the intervals can only have been
+        // rearranged among themselves
+        let images: IntervalSet = self
+            .pieces
+            .iter()
+            .map(|(interval, offset)| interval.shift(*offset))
+            .collect();
+        if images != self.sources() {
+            return None;
+        }
+        let mut inverse = OffsetMap::new();
+        for (interval, offset) in &self.pieces {
+            inverse.insert(interval.shift(*offset), -offset);
+        }
+        Some(inverse)
+    }
+
+    /// Splits an interval into the parts with different offsets, in ascending order, where the
+    /// parts without an interval in the map have an offset of 0.
+    fn segments(&self, interval: Interval) -> Vec<(Interval, i64)> {
+        let mut segments = vec![];
+        let mut start = interval.start;
+        let first = self.pieces.partition_point(|(piece, _)| piece.end <= start);
+        for (piece, offset) in self.pieces[first..]
+            .iter()
+            .take_while(|(piece, _)| piece.start < interval.end)
+        {
+            if start < piece.start {
+                segments.push((Interval::new(start, piece.start), 0));
+            }
+            segments.push((piece.intersection(interval).unwrap(), *offset));
+            start = piece.end;
+        }
+        if start < interval.end {
+            segments.push((Interval::new(start, interval.end), 0));
+        }
+        segments
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The seed-to-soil and soil-to-fertilizer maps of the example of 2023 day 5.
+    fn maps() -> (OffsetMap, OffsetMap) {
+        let mut soil = OffsetMap::new();
+        soil.insert(Interval::with_len(98, 2), 50 - 98);
+        soil.insert(Interval::with_len(50, 48), 52 - 50);
+        let mut fertilizer = OffsetMap::new();
+        fertilizer.insert(Interval::with_len(15, 37), 0 - 15);
+        fertilizer.insert(Interval::with_len(52, 2), 37 - 52);
+        fertilizer.insert(Interval::with_len(0, 15), 39);
+        (soil, fertilizer)
+    }
+
+    #[test]
+    fn maps_integers_and_sets() {
+        let (soil, _) = maps();
+        let seeds = [79, 14, 55, 13];
+        assert_eq!(seeds.map(|seed| soil.apply(seed)), [81, 14, 57, 13]);
+        assert_eq!(soil.apply(99), 51);
+        assert_eq!(soil.apply(100), 100);
+
+        let set: IntervalSet = [Interval::with_len(79, 14), Interval::with_len(95, 10)]
+            .into_iter()
+            .collect();
+        let expected: IntervalSet = [
+            Interval::new(50, 52),
+            Interval::new(81, 95),
+            Interval::new(97, 105),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(soil.apply_to_set(&set), expected);
+    }
+
+    #[test]
+    fn composes_maps() {
+        let (soil, fertilizer) = maps();
+        let composed = soil.then(&fertilizer);
+        for seed in -5..110 {
+            assert_eq!(composed.apply(seed), fertilizer.apply(soil.apply(seed)));
+        }
+        assert_eq!(OffsetMap::new().then(&soil), soil);
+        assert_eq!(soil.then(&OffsetMap::new()), soil);
+    }
+
+    #[test]
+    fn inverts_rearrangements() {
+        let (soil, fertilizer) = maps();
+        let inverse = soil.then(&fertilizer).inverse().unwrap();
+        for seed in -5..110 {
+            assert_eq!(inverse.apply(fertilizer.apply(soil.apply(seed))), seed);
+        }
+        assert_eq!(soil.inverse().unwrap().inverse(), Some(soil));
+
+        // 10 to 14 end up where they would have stayed
+        let mut shift = OffsetMap::new();
+        shift.insert(Interval::new(0, 10), 5);
+        assert_eq!(shift.inverse(), None);
+    }
+}
@@ -0,0 +1,229 @@
+//! Divisors, modular arithmetic and the Chinese remainder theorem, for puzzles about things that
+//! repeat with different periods.
+
+use std::ops::Div;
+
+/// The primitive integers that `gcd` and `lcm` work with.
+pub trait Integer: Copy + Eq + Div<Output = Self> {
+    const ZERO: Self;
+
+    fn checked_rem(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_abs(self) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    (unsigned $($t:ty),*) => {$(
+        impl Integer for $t {
+            const ZERO: Self = 0;
+
+            fn checked_rem(self, other: Self) -> Option<Self> {
+                <$t>::checked_rem(self, other)
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$t>::checked_mul(self, other)
+            }
+
+            fn checked_abs(self) -> Option<Self> {
+                Some(self)
+            }
+        }
+    )*};
+    (signed $($t:ty),*) => {$(
+        impl Integer for $t {
+            const ZERO: Self = 0;
+
+            fn checked_rem(self, other: Self) -> Option<Self> {
+                <$t>::checked_rem(self, other)
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$t>::checked_mul(self, other)
+            }
+
+            fn checked_abs(self) -> Option<Self> {
+                <$t>::checked_abs(self)
+            }
+        }
+    )*};
+}
+
+impl_integer!(unsigned u8, u16, u32, u64, u128, usize);
+impl_integer!(signed i8, i16, i32, i64, i128, isize);
+
+/// Returns the greatest common divisor, which is never negative and only 0 if both numbers are.
+/// Panics if it doesn't fit, which only happens for the minimum of a signed type and 0 or itself.
+pub fn gcd<T: Integer>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        // Only `MIN % -1` overflows, and every number is divisible by -1
+        (a, b) = (b, a.checked_rem(b).unwrap_or(T::ZERO));
+    }
+    a.checked_abs()
+        .expect("The greatest common divisor is too large for its type")
+}
+
+/// Returns the least common multiple, which is never negative and 0 if either number is, unless
+/// it doesn't fit.
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    (a / gcd(a, b)).checked_mul(b)?.checked_abs()
+}
+
+/// Returns the greatest common divisor `g` of `a` and `b` with the coefficients `x` and `y` for
+/// which `a * x + b * y == g`, as `(g, x, y)`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Returns the `x` between 0 and `modulus` for which `a * x ≡ 1 (mod modulus)`, unless `a` and
+/// `modulus` have a common divisor.
+pub fn mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+    assert!(
+        modulus > 0,
+        "The modulus should be positive, not {}",
+        modulus
+    );
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// Returns `a * b % modulus` without overflowing, given that `a` and `b` are below `modulus`.
+pub fn mod_mul(a: u128, b: u128, modulus: u128) -> u128 {
+    if let Some(product) = a.checked_mul(b) {
+        return product % modulus;
+    }
+    // Double and add, which keeps every value below the modulus
+    let add = |a: u128, b: u128| {
+        if a >= modulus - b {
+            a - (modulus - b)
+        } else {
+            a + b
+        }
+    };
+    let (mut a, mut b, mut product) = (a, b, 0);
+    while b > 0 {
+        if b & 1 == 1 {
+            product = add(product, a);
+        }
+        a = add(a, a);
+        b >>= 1;
+    }
+    product
+}
+
+/// Returns `base` to the power of `exponent`, modulo `modulus`, by repeated squaring.
+pub fn mod_pow(base: u128, mut exponent: u128, modulus: u128) -> u128 {
+    assert!(modulus > 0, "The modulus should be positive, not 0");
+    let mut base = base % modulus;
+    let mut power = 1 % modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            power = mod_mul(power, base, modulus);
+        }
+        base = mod_mul(base, base, modulus);
+        exponent >>= 1;
+    }
+    power
+}
+
+/// Solves a system of congruences `x ≡ residue (mod modulus)`, given as pairs of a residue and a
+/// positive modulus that don't have to be coprime. Returns the smallest `x` that isn't negative
+/// with the least common multiple of the moduli, after which the solutions repeat, or `None` if
+/// the congruences contradict each other or the least common multiple doesn't fit.
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Option<(i128, i128)> {
+    let (mut x, mut period) = (0, 1);
+    for (residue, modulus) in congruences {
+        assert!(
+            modulus > 0,
+            "The modulus should be positive, not {}",
+            modulus
+        );
+        let difference = residue.rem_euclid(modulus) - x;
+        let g = gcd(period, modulus);
+        if difference % g != 0 {
+            return None;
+        }
+        // Step by the current period until the new congruence holds, which takes k steps with
+        // period * k ≡ difference (mod modulus)
+        let steps = modulus / g;
+        let inverse = mod_inverse(period / g, steps).unwrap();
+        let k = mod_mul(
+            (difference / g).rem_euclid(steps) as u128,
+            inverse as u128,
+            steps as u128,
+        ) as i128;
+        let next_period = period.checked_mul(steps)?;
+        x += period * k;
+        period = next_period;
+    }
+    Some((x, period))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_divisors_and_multiples() {
+        assert_eq!(gcd(12u64, 18), 6);
+        assert_eq!(gcd(-12i128, 18), 6);
+        assert_eq!(gcd(0u128, 0), 0);
+        assert_eq!(gcd(i128::MIN, -1), 1);
+        assert_eq!(lcm(4u64, 6), Some(12));
+        assert_eq!(lcm(-4i128, 6), Some(12));
+        assert_eq!(lcm(0u64, 6), Some(0));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(
+            lcm(u64::MAX as u128, u64::MAX as u128 - 1),
+            Some(u64::MAX as u128 * (u64::MAX as u128 - 1))
+        );
+    }
+
+    #[test]
+    fn inverts_modulo() {
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+        assert_eq!(extended_gcd(-4, 6).0, 2);
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(4, 6), None);
+    }
+
+    #[test]
+    fn raises_to_powers_without_overflowing() {
+        assert_eq!(mod_pow(2, 10, 1000), 24);
+        assert_eq!(mod_pow(5, 0, 1), 0);
+        // By Fermat's little theorem, for the prime 2^127 - 1
+        let prime = (1 << 127) - 1;
+        assert_eq!(mod_pow(3, prime - 1, prime), 1);
+        assert_eq!(mod_mul(prime - 1, prime - 1, prime), 1);
+    }
+
+    #[test]
+    fn solves_congruences() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt([(-1, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([]), Some((0, 1)));
+        // The least common multiple needs about 200 bits
+        assert_eq!(crt([(0, 1 << 100), (0, (1 << 100) + 1)]), None);
+    }
+}
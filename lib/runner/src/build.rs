@@ -0,0 +1,85 @@
+//! Generates the example tests of a day from its manifest, in the build script of its
+//! `solution` crate:
+//!
+//! ```ignore
+//! fn main() {
+//!     aoc_runner::build::example_tests();
+//! }
+//! ```
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::manifest::{self, Manifest};
+use crate::Part;
+
+/// The file in `OUT_DIR` that [`example_tests!`](crate::example_tests) includes.
+pub const TESTS_FILE: &str = "example_tests.rs";
+
+/// Writes a test per example answer in `../day.toml` to [`TESTS_FILE`].
+pub fn example_tests() {
+    let dir = Path::new("..");
+    println!(
+        "cargo:rerun-if-changed={}",
+        dir.join(manifest::FILE_NAME).display()
+    );
+    let manifest = Manifest::load(dir).unwrap_or_else(|e| panic!("{}", e));
+    let out = PathBuf::from(env::var_os("OUT_DIR").expect("Cargo should set OUT_DIR"));
+    let path = out.join(TESTS_FILE);
+    fs::write(&path, generate(&manifest))
+        .unwrap_or_else(|e| panic!("Could not write {}: {}", path.display(), e));
+}
+
+/// Returns the source of a test per part of each example that has an expected answer, named
+/// after the position of the example in the manifest.
+pub fn generate(manifest: &Manifest) -> String {
+    let mut tests = String::new();
+    for (index, example) in manifest.examples.iter().enumerate() {
+        for part in Part::ALL {
+            if example.answer(part).is_none() {
+                continue;
+            }
+            writeln!(
+                tests,
+                "#[test]\n\
+                 fn example_{}_part_{}() {{\n    \
+                 ::aoc_runner::test_example::<ExampleSolution>({}, ::aoc_runner::Part::{:?});\n\
+                 }}",
+                index + 1,
+                part,
+                index,
+                part
+            )
+            .unwrap();
+        }
+    }
+    tests
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generates_a_test_per_answer() {
+        let manifest = Manifest::parse(
+            "[[example]]\ninput = \"input-example-1\"\npart1 = 114\n\n\
+             [[example]]\ninput = \"input-example-2\"\npart2 = 2\n",
+        )
+        .unwrap();
+        assert_eq!(
+            generate(&manifest),
+            "#[test]\n\
+             fn example_1_part_1() {\n    \
+             ::aoc_runner::test_example::<ExampleSolution>(0, ::aoc_runner::Part::One);\n\
+             }\n\
+             #[test]\n\
+             fn example_2_part_2() {\n    \
+             ::aoc_runner::test_example::<ExampleSolution>(1, ::aoc_runner::Part::Two);\n\
+             }\n"
+        );
+        assert_eq!(generate(&Manifest::default()), "");
+    }
+}
@@ -0,0 +1,235 @@
+use std::env;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// The environment variable that names the input file to use instead of the day's own.
+pub const ENV_VAR: &str = "AOC_INPUT";
+
+/// Where to read the puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Source {
+    /// The `input` file of the day, unless `AOC_INPUT` names another one.
+    #[default]
+    Default,
+    /// A file, relative to the current directory.
+    File(PathBuf),
+    Stdin,
+    /// The `input-example-N` file of the day.
+    Example(u32),
+}
+
+impl Source {
+    /// Parses a path given on the command line, where `-` stands for stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::File(PathBuf::from(path)),
+        }
+    }
+
+    /// Parses the arguments of a day's binary: nothing, a path, `-`, or `--example N`.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let args: Vec<String> = args.into_iter().collect();
+        match args.as_slice() {
+            [] => Ok(Source::Default),
+            [flag, n] if flag == "--example" => n
+                .parse()
+                .map(Source::Example)
+                .map_err(|_| format!("Expected an example number, got {:?}", n)),
+            [arg] if !arg.starts_with("--") => Ok(Source::from_arg(arg)),
+            _ => Err(format!(
+                "Unexpected arguments {:?}, expected [PATH | - | --example N]",
+                args
+            )),
+        }
+    }
+
+    /// Returns the file to read for the day in `dir`, or `None` for stdin, given the value of
+    /// `AOC_INPUT`.
+    fn resolve(&self, dir: &Path, env: Option<&str>) -> Option<PathBuf> {
+        match self {
+            Source::Default => match env.filter(|value| !value.is_empty()) {
+                Some(value) => Source::from_arg(value).resolve(dir, None),
+                None => Some(dir.join("input")),
+            },
+            Source::File(path) => Some(path.clone()),
+            Source::Stdin => None,
+            Source::Example(n) => Some(dir.join(format!("input-example-{}", n))),
+        }
+    }
+
+    /// Returns the file this source reads for the day in `dir`, or `None` for stdin.
+    pub fn path(&self, dir: &Path) -> Option<PathBuf> {
+        self.resolve(dir, env::var(ENV_VAR).ok().as_deref())
+    }
+
+    /// Reads the input of the day in `dir`.
+    pub fn read(&self, dir: &Path) -> Result<String, String> {
+        match self.path(dir) {
+            Some(path) => fs::read_to_string(&path)
+                .map_err(|e| format!("Could not read {}: {}", path.display(), e)),
+            None => {
+                let mut content = String::new();
+                io::stdin()
+                    .read_to_string(&mut content)
+                    .map_err(|e| format!("Could not read stdin: {}", e))?;
+                Ok(content)
+            }
+        }
+    }
+}
+
+/// Normalizes an input so that parsers can rely on its shape: strips a byte order mark, turns
+/// CRLF and CR line endings into LF, removes trailing blank lines if `trim_blank_lines` is set,
+/// and ends the input with a single newline unless it is empty.
+pub fn normalize(raw: &str, trim_blank_lines: bool) -> String {
+    let text = raw.strip_prefix('\u{feff}').unwrap_or(raw);
+    let mut text = text.replace("\r\n", "\n").replace('\r', "\n");
+    if trim_blank_lines {
+        let mut lines: Vec<&str> = text.lines().collect();
+        while lines.last().is_some_and(|line| line.trim().is_empty()) {
+            lines.pop();
+        }
+        text = lines.join("\n");
+    }
+    if !text.is_empty() && !text.ends_with('\n') {
+        text.push('\n');
+    }
+    text
+}
+
+/// Characters in an input that are valid, but are often a mistake, such as tabs pasted from a
+/// puzzle page where spaces were expected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warning {
+    /// What was found, such as "tab".
+    pub what: &'static str,
+    pub count: usize,
+    /// The line and column of the first occurrence, counting from 1.
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let plural = if self.count == 1 { "" } else { "s" };
+        write!(
+            f,
+            "found {} {}{}, the first at line {}, column {}",
+            self.count, self.what, plural, self.line, self.column
+        )
+    }
+}
+
+/// Returns a warning about the characters of an input that match, if there are any.
+fn find(input: &str, what: &'static str, matches: impl Fn(char) -> bool) -> Option<Warning> {
+    let mut found = input.lines().enumerate().flat_map(|(y, line)| {
+        line.chars()
+            .enumerate()
+            .filter(|(_, c)| matches(*c))
+            .map(move |(x, _)| (y + 1, x + 1))
+    });
+    let (line, column) = found.next()?;
+    Some(Warning {
+        what,
+        count: found.count() + 1,
+        line,
+        column,
+    })
+}
+
+/// Returns a warning for each kind of suspicious character in an input: tabs and non-ASCII
+/// characters.
+pub fn warnings(input: &str) -> Vec<Warning> {
+    [
+        find(input, "tab", |c| c == '\t'),
+        find(input, "non-ASCII character", |c| !c.is_ascii()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Result<Source, String> {
+        Source::from_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parses_arguments() {
+        assert_eq!(args(&[]), Ok(Source::Default));
+        assert_eq!(args(&["-"]), Ok(Source::Stdin));
+        assert_eq!(args(&["in.txt"]), Ok(Source::File("in.txt".into())));
+        assert_eq!(args(&["--example", "2"]), Ok(Source::Example(2)));
+        assert!(args(&["--example", "two"]).is_err());
+        assert!(args(&["--verbose"]).is_err());
+        assert!(args(&["a", "b"]).is_err());
+    }
+
+    #[test]
+    fn resolves_files() {
+        let dir = Path::new("2023/day_1");
+        assert_eq!(Source::Default.resolve(dir, None), Some(dir.join("input")));
+        assert_eq!(
+            Source::Default.resolve(dir, Some("other")),
+            Some(PathBuf::from("other"))
+        );
+        assert_eq!(Source::Default.resolve(dir, Some("-")), None);
+        assert_eq!(
+            Source::Example(3).resolve(dir, Some("other")),
+            Some(dir.join("input-example-3"))
+        );
+    }
+
+    #[test]
+    fn names_missing_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let error = Source::Example(7).read(dir.path()).unwrap_err();
+        assert!(error.starts_with(&format!(
+            "Could not read {}",
+            dir.path().join("input-example-7").display()
+        )));
+    }
+
+    #[test]
+    fn normalizes_line_endings() {
+        assert_eq!(normalize("\u{feff}a\r\nb\rc", true), "a\nb\nc\n");
+        assert_eq!(normalize("a\n\nb\n \n\n", true), "a\n\nb\n");
+        assert_eq!(normalize("a\r\n\r\n", false), "a\n\n");
+        assert_eq!(normalize("a", false), "a\n");
+        assert_eq!(normalize("", true), "");
+        assert_eq!(normalize("\n\n", true), "");
+    }
+
+    #[test]
+    fn warns_about_tabs_and_non_ascii() {
+        assert_eq!(warnings("a b\nc d\n"), []);
+        let found = warnings("a\tb\n\tcé\n");
+        assert_eq!(
+            found,
+            [
+                Warning {
+                    what: "tab",
+                    count: 2,
+                    line: 1,
+                    column: 2
+                },
+                Warning {
+                    what: "non-ASCII character",
+                    count: 1,
+                    line: 2,
+                    column: 3
+                }
+            ]
+        );
+        assert_eq!(
+            found[0].to_string(),
+            "found 2 tabs, the first at line 1, column 2"
+        );
+    }
+}
@@ -0,0 +1,474 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::fmt::{self, Debug, Display};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::str::FromStr;
+
+use crate::input::Source;
+use crate::manifest::Manifest;
+use crate::parse::ParseError;
+
+pub mod build;
+pub mod input;
+pub mod manifest;
+pub mod parse;
+
+/// One of the two parts of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// Returns the number of the part, as used on the puzzle pages.
+    pub fn number(self) -> u8 {
+        self.index() as u8 + 1
+    }
+
+    /// Returns the part with the given number.
+    pub fn from_number(number: u8) -> Option<Self> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    fn index(self) -> usize {
+        match self {
+            Part::One => 0,
+            Part::Two => 1,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.index() + 1)
+    }
+}
+
+/// Named parameters of a part, such as the number of steps to simulate.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    pub fn new(values: &[(&str, &str)]) -> Self {
+        Self {
+            values: values
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+        }
+    }
+
+    /// Returns the parsed value of a parameter.
+    ///
+    /// Panics when the parameter is missing or can't be parsed, as the parameters a part accepts
+    /// are declared by the solution itself.
+    pub fn get<T>(&self, name: &str) -> T
+    where
+        T: FromStr,
+        T::Err: Debug,
+    {
+        let value = self
+            .values
+            .get(name)
+            .unwrap_or_else(|| panic!("Missing parameter: {}", name));
+        value
+            .parse()
+            .unwrap_or_else(|e| panic!("Invalid value for parameter {}: {:?}", name, e))
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.values.contains_key(name)
+    }
+
+    pub fn set(&mut self, name: &str, value: &str) {
+        self.values.insert(name.to_string(), value.to_string());
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    /// Sets the values of `overrides` for the parameters these already hold, ignoring the rest.
+    pub fn override_with(&mut self, overrides: &Params) {
+        for (name, value) in overrides.iter() {
+            if self.contains(name) {
+                self.set(name, value);
+            }
+        }
+    }
+}
+
+/// A puzzle solution: a parse step shared by both parts, and a solver per part.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    /// The parameters part 1 accepts, with their default values.
+    const PART1_PARAMS: &'static [(&'static str, &'static str)] = &[];
+    /// The parameters part 2 accepts, with their default values.
+    const PART2_PARAMS: &'static [(&'static str, &'static str)] = &[];
+    /// Whether blank lines at the end of the input are removed before it is parsed.
+    const TRIM_BLANK_LINES: bool = true;
+
+    type Input: 'static;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input, params: &Params) -> Self::Answer1;
+    fn part2(input: &Self::Input, params: &Params) -> Self::Answer2;
+
+    /// Solves part 1 using the default parameters, panicking on an invalid input.
+    fn solve_part1(input: &str) -> Self::Answer1 {
+        Self::part1(
+            &parse_or_panic::<Self>(input),
+            &Params::new(Self::PART1_PARAMS),
+        )
+    }
+
+    /// Solves part 2 using the default parameters, panicking on an invalid input.
+    fn solve_part2(input: &str) -> Self::Answer2 {
+        Self::part2(
+            &parse_or_panic::<Self>(input),
+            &Params::new(Self::PART2_PARAMS),
+        )
+    }
+}
+
+fn parse_or_panic<S: Solution + ?Sized>(input: &str) -> S::Input {
+    let input = input::normalize(input, S::TRIM_BLANK_LINES);
+    S::parse(&input).unwrap_or_else(|e| panic!("{}", e.render(&input)))
+}
+
+/// A type-erased [`Solution`], so days with different input and answer types can be registered
+/// side by side.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    params: [&'static [(&'static str, &'static str)]; 2],
+    trim_blank_lines: bool,
+    parse: fn(&str) -> Result<Box<dyn Any>, ParseError>,
+    parts: [fn(&dyn Any, &Params) -> String; 2],
+}
+
+impl Debug for Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Day")
+            .field("year", &self.year)
+            .field("day", &self.day)
+            .finish()
+    }
+}
+
+fn parse_erased<S: Solution>(input: &str) -> Result<Box<dyn Any>, ParseError> {
+    Ok(Box::new(S::parse(input)?))
+}
+
+fn part1_erased<S: Solution>(input: &dyn Any, params: &Params) -> String {
+    let input = input
+        .downcast_ref()
+        .expect("Input should be parsed by the same day");
+    S::part1(input, params).to_string()
+}
+
+fn part2_erased<S: Solution>(input: &dyn Any, params: &Params) -> String {
+    let input = input
+        .downcast_ref()
+        .expect("Input should be parsed by the same day");
+    S::part2(input, params).to_string()
+}
+
+impl Day {
+    pub const fn of<S: Solution>() -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            params: [S::PART1_PARAMS, S::PART2_PARAMS],
+            trim_blank_lines: S::TRIM_BLANK_LINES,
+            parse: parse_erased::<S>,
+            parts: [part1_erased::<S>, part2_erased::<S>],
+        }
+    }
+
+    /// Returns the default parameters of a part.
+    pub fn params(&self, part: Part) -> Params {
+        Params::new(self.params[part.index()])
+    }
+
+    /// Normalizes an input the way this day expects, see [`input::normalize`].
+    pub fn normalize(&self, raw: &str) -> String {
+        input::normalize(raw, self.trim_blank_lines)
+    }
+
+    /// Reads and normalizes an input of the day in `dir`, printing warnings about suspicious
+    /// characters in it.
+    pub fn load(&self, source: &Source, dir: &Path) -> Result<String, String> {
+        let input = self.normalize(&source.read(dir)?);
+        for warning in input::warnings(&input) {
+            eprintln!("Warning: {}", warning);
+        }
+        Ok(input)
+    }
+
+    /// Parses an input, which should be normalized by [`Day::normalize`] or [`Day::load`].
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        (self.parse)(input)
+    }
+
+    /// Solves a part for an input returned by [`Day::parse`].
+    pub fn solve_part(&self, input: &dyn Any, part: Part, params: &Params) -> String {
+        (self.parts[part.index()])(input, params)
+    }
+
+    /// Solves both parts with the given parameters for each part.
+    pub fn solve(&self, input: &str, params: &[Params; 2]) -> Result<(String, String), ParseError> {
+        let parsed = self.parse(input)?;
+        let [answer_1, answer_2] =
+            Part::ALL.map(|part| self.solve_part(parsed.as_ref(), part, &params[part.index()]));
+        Ok((answer_1, answer_2))
+    }
+
+    /// Returns whether any part of this day accepts the given parameter.
+    pub fn accepts(&self, name: &str) -> bool {
+        self.params
+            .iter()
+            .any(|params| params.iter().any(|(n, _)| *n == name))
+    }
+}
+
+/// Prints the answers for both parts of a day.
+pub fn print_answers(answers: &(String, String)) {
+    println!("Solution part 1: {}", answers.0);
+    println!("Solution part 2: {}", answers.1);
+}
+
+/// Returns the root of the repository, which holds a directory per year.
+pub fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .ancestors()
+        .nth(2)
+        .expect("The runner should live in lib/runner")
+}
+
+/// Returns the directory of a day, which holds its inputs and the `solution` crate.
+pub fn day_dir(year: u16, day: u8) -> PathBuf {
+    root().join(year.to_string()).join(format!("day_{}", day))
+}
+
+/// Entry point of the per-day binaries. Reads the day's `input`, or the input given by the
+/// arguments or `AOC_INPUT`, and the parameters from its `day.toml`. An invalid input is
+/// reported with the offending line.
+pub fn main<S: Solution>() {
+    let day = Day::of::<S>();
+    let dir = day_dir(day.year, day.day);
+    let answers = Source::from_args(std::env::args().skip(1)).and_then(|source| {
+        let content = day.load(&source, &dir)?;
+        let manifest = Manifest::load(&dir)?;
+        let params = [
+            manifest.source_params(&day, &source, Part::One)?,
+            manifest.source_params(&day, &source, Part::Two)?,
+        ];
+        day.solve(&content, &params).map_err(|e| e.render(&content))
+    });
+    match answers {
+        Ok(answers) => print_answers(&answers),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Declares a test per example answer listed in the day's `day.toml`, which its build script
+/// generates with [`build::example_tests`]. Meant for the `tests` module of a day:
+///
+/// ```ignore
+/// aoc_runner::example_tests!(Day9);
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty) => {
+        type ExampleSolution = $solution;
+        include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
+    };
+}
+
+/// Solves a part of the example at `index` in `../day.toml` and panics unless the answer is the
+/// expected one. Called by the tests of [`example_tests!`], which run in the `solution` directory
+/// of a day.
+pub fn test_example<S: Solution>(index: usize, part: Part) {
+    check_example(&Day::of::<S>(), Path::new(".."), index, part)
+        .unwrap_or_else(|e| panic!("{}", e));
+}
+
+/// Solves a part of the example at `index` in the manifest of the day in `dir`, returning why
+/// it failed. A part without an expected answer passes.
+fn check_example(day: &Day, dir: &Path, index: usize, part: Part) -> Result<(), String> {
+    let manifest = Manifest::load(dir)?;
+    let example = manifest
+        .examples
+        .get(index)
+        .ok_or_else(|| format!("The manifest has no example {}", index + 1))?;
+    let Some(expected) = example.answer(part) else {
+        return Ok(());
+    };
+    let content = day.load(&Source::File(dir.join(&example.input)), dir)?;
+    let input = day
+        .parse(&content)
+        .map_err(|e| format!("{}: {}", example.input, e.render(&content)))?;
+    let params = manifest.example_params(day, example, part)?;
+    let answer = day.solve_part(input.as_ref(), part, &params);
+    if answer == expected.0 {
+        Ok(())
+    } else {
+        Err(format!(
+            "{} part {}: expected {}, got {}",
+            example.input, part, expected, answer
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    struct Example;
+
+    impl Solution for Example {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 1;
+        const PART2_PARAMS: &'static [(&'static str, &'static str)] = &[("repeat", "2")];
+
+        type Input = Vec<char>;
+        type Answer1 = usize;
+        type Answer2 = String;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            let letters = input.trim_end();
+            match letters.find(char::is_whitespace) {
+                Some(i) => Err(ParseError::at(input, &letters[i..i + 1], "a letter")),
+                None => Ok(letters.chars().collect()),
+            }
+        }
+
+        fn part1(input: &Self::Input, _params: &Params) -> usize {
+            input.len()
+        }
+
+        fn part2(input: &Self::Input, params: &Params) -> String {
+            input
+                .iter()
+                .collect::<String>()
+                .repeat(params.get("repeat"))
+        }
+    }
+
+    #[test]
+    fn solves_both_parts() {
+        let day = Day::of::<Example>();
+        assert_eq!(
+            day.solve("abc", &Part::ALL.map(|part| day.params(part))),
+            Ok(("3".to_string(), "abcabc".to_string()))
+        );
+    }
+
+    #[test]
+    fn overrides_accepted_params_only() {
+        let day = Day::of::<Example>();
+        let mut params = day.params(Part::Two);
+        params.override_with(&Params::new(&[("repeat", "3"), ("unknown", "1")]));
+        assert_eq!(params, Params::new(&[("repeat", "3")]));
+        assert!(day.accepts("repeat"));
+        assert!(!day.accepts("unknown"));
+    }
+
+    #[test]
+    fn checks_examples_with_their_params() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("input-example-1"), "abc").unwrap();
+        let manifest = "[params.part2]\nrepeat = 3\n\n\
+                        [[example]]\ninput = \"input-example-1\"\npart1 = 3\npart2 = \"abcabcabc\"\n\n\
+                        [[example]]\ninput = \"input-example-1\"\npart2 = \"abc\"\n\
+                        params.part2 = { repeat = 1 }\n";
+        fs::write(dir.path().join(manifest::FILE_NAME), manifest).unwrap();
+        let day = Day::of::<Example>();
+        let check = |index, part| check_example(&day, dir.path(), index, part);
+        assert_eq!(check(0, Part::One), Ok(()));
+        assert_eq!(check(0, Part::Two), Ok(()));
+        assert_eq!(check(1, Part::One), Ok(()));
+        assert_eq!(check(1, Part::Two), Ok(()));
+        assert_eq!(
+            check(2, Part::One),
+            Err("The manifest has no example 3".to_string())
+        );
+
+        let manifest = manifest.replace("part1 = 3", "part1 = 4");
+        fs::write(dir.path().join(manifest::FILE_NAME), manifest).unwrap();
+        assert_eq!(
+            check(0, Part::One),
+            Err("input-example-1 part 1: expected 4, got 3".to_string())
+        );
+
+        fs::write(dir.path().join("input-example-1"), "ab\nc").unwrap();
+        assert_eq!(
+            check(0, Part::Two).unwrap_err().lines().next(),
+            Some("input-example-1: Invalid input at line 1, column 3: expected a letter, found \"\\n\"")
+        );
+    }
+
+    #[test]
+    fn solves_examples_with_their_params() {
+        let day = Day::of::<Example>();
+        let manifest = Manifest::parse(
+            "[[example]]\ninput = \"input-example-2\"\nparams.part2 = { repeat = 5 }\n",
+        )
+        .unwrap();
+        let params = |source| manifest.source_params(&day, &source, Part::Two).unwrap();
+        assert_eq!(params(Source::Example(2)), Params::new(&[("repeat", "5")]));
+        assert_eq!(params(Source::Example(1)), Params::new(&[("repeat", "2")]));
+        assert_eq!(params(Source::Default), Params::new(&[("repeat", "2")]));
+    }
+
+    #[test]
+    fn rejects_unknown_params_in_manifest() {
+        let day = Day::of::<Example>();
+        let manifest = Manifest::parse("[params.part1]\nrepeat = 3\n").unwrap();
+        assert_eq!(
+            manifest.params(&day, Part::Two),
+            Ok(Params::new(&[("repeat", "2")]))
+        );
+        assert_eq!(
+            manifest.params(&day, Part::One),
+            Err("Part 1 has no parameter repeat".to_string())
+        );
+    }
+
+    #[test]
+    fn solves_with_default_params() {
+        assert_eq!(Example::solve_part1("abcd"), 4);
+        assert_eq!(Example::solve_part2("ab"), "abab");
+    }
+
+    #[test]
+    fn normalizes_inputs() {
+        assert_eq!(Example::solve_part1("\u{feff}abcd\r\n\r\n"), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a letter")]
+    fn panics_on_invalid_input() {
+        Example::solve_part1("a b");
+    }
+}
@@ -0,0 +1,236 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::input::Source;
+use crate::{Day, Params, Part};
+
+/// The file name of the manifest in the directory of a day.
+pub const FILE_NAME: &str = "day.toml";
+
+/// An expected answer or a parameter value. Numbers are written as TOML integers, anything else
+/// as a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Value(pub String);
+
+impl Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Serialize for Value {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Only numbers that read back the same, unlike "0123" or "+5", are written as numbers
+        match self.0.parse::<i64>() {
+            Ok(number) if number.to_string() == self.0 => serializer.serialize_i64(number),
+            _ => serializer.serialize_str(&self.0),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Number(i64),
+            Text(String),
+        }
+        Ok(Value(match Raw::deserialize(deserializer)? {
+            Raw::Number(number) => number.to_string(),
+            Raw::Text(text) => text,
+        }))
+    }
+}
+
+/// Parameter values by part, such as `[params.part1]`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PartParams {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub part1: BTreeMap<String, Value>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub part2: BTreeMap<String, Value>,
+}
+
+impl PartParams {
+    pub fn get(&self, part: Part) -> &BTreeMap<String, Value> {
+        match part {
+            Part::One => &self.part1,
+            Part::Two => &self.part2,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.part1.is_empty() && self.part2.is_empty()
+    }
+
+    /// Sets the values of a part in `params`, which should already hold every parameter the part
+    /// accepts.
+    fn apply(&self, part: Part, params: &mut Params) -> Result<(), String> {
+        for (name, value) in self.get(part) {
+            if !params.contains(name) {
+                return Err(format!("Part {} has no parameter {}", part, name));
+            }
+            params.set(name, &value.0);
+        }
+        Ok(())
+    }
+}
+
+/// An example input with the answers the puzzle text gives for it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Example {
+    /// The file holding the input, relative to the directory of the day.
+    pub input: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<Value>,
+    /// Parameters that differ from the defaults for this example, such as a smaller number of
+    /// steps.
+    #[serde(default, skip_serializing_if = "PartParams::is_empty")]
+    pub params: PartParams,
+}
+
+impl Example {
+    pub fn answer(&self, part: Part) -> Option<&Value> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+}
+
+/// The manifest of a day, `day.toml`, which lives next to its inputs.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Manifest {
+    /// Defaults of the parameters, which take precedence over those of the solution.
+    #[serde(default, skip_serializing_if = "PartParams::is_empty")]
+    pub params: PartParams,
+    #[serde(default, rename = "example", skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<Example>,
+}
+
+impl Manifest {
+    /// Loads the manifest of the day in `dir`, which is empty when the day has none.
+    pub fn load(dir: &Path) -> Result<Self, String> {
+        let path = dir.join(FILE_NAME);
+        match fs::read_to_string(&path) {
+            Ok(content) => Self::parse(&content)
+                .map_err(|e| format!("Invalid manifest {}: {}", path.display(), e)),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self, String> {
+        toml::from_str(content).map_err(|e| e.to_string())
+    }
+
+    /// Returns the parameters of a part of `day`: the defaults of the solution, overridden by
+    /// those of the manifest.
+    pub fn params(&self, day: &Day, part: Part) -> Result<Params, String> {
+        let mut params = day.params(part);
+        self.params.apply(part, &mut params)?;
+        Ok(params)
+    }
+
+    /// Returns the parameters to solve a part of an example with.
+    pub fn example_params(
+        &self,
+        day: &Day,
+        example: &Example,
+        part: Part,
+    ) -> Result<Params, String> {
+        let mut params = self.params(day, part)?;
+        example
+            .params
+            .apply(part, &mut params)
+            .map_err(|e| format!("{}: {}", example.input, e))?;
+        Ok(params)
+    }
+
+    /// Returns the parameters to solve a part of an input with. Examples are solved with the
+    /// parameters of their first entry in the manifest.
+    pub fn source_params(&self, day: &Day, source: &Source, part: Part) -> Result<Params, String> {
+        let Source::Example(n) = source else {
+            return self.params(day, part);
+        };
+        let input = format!("input-example-{}", n);
+        match self.examples.iter().find(|example| example.input == input) {
+            Some(example) => self.example_params(day, example, part),
+            None => self.params(day, part),
+        }
+    }
+
+    pub fn save(&self, dir: &Path) -> Result<(), String> {
+        let path = dir.join(FILE_NAME);
+        let content = toml::to_string(self).expect("Manifests should serialize");
+        fs::write(&path, content).map_err(|e| format!("Could not write {}: {}", path.display(), e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"[[example]]
+input = "input-example-1"
+part1 = 114
+part2 = 2
+
+[[example]]
+input = "input-example-2"
+part2 = "abc"
+"#;
+
+    #[test]
+    fn parses_examples() {
+        let manifest = Manifest::parse(MANIFEST).unwrap();
+        assert_eq!(manifest.examples.len(), 2);
+        assert_eq!(manifest.examples[0].input, "input-example-1");
+        assert_eq!(
+            manifest.examples[0].answer(Part::One),
+            Some(&Value("114".to_string()))
+        );
+        assert_eq!(manifest.examples[1].answer(Part::One), None);
+        assert_eq!(
+            manifest.examples[1].answer(Part::Two),
+            Some(&Value("abc".to_string()))
+        );
+    }
+
+    #[test]
+    fn writes_what_it_reads() {
+        let manifest = Manifest::parse(MANIFEST).unwrap();
+        assert_eq!(toml::to_string(&manifest).unwrap(), MANIFEST);
+    }
+
+    #[test]
+    fn keeps_leading_zeros() {
+        let example = Example {
+            input: "input-example-1".to_string(),
+            part1: Some(Value("0123".to_string())),
+            part2: Some(Value("-5".to_string())),
+            params: PartParams::default(),
+        };
+        let manifest = Manifest {
+            params: PartParams::default(),
+            examples: vec![example],
+        };
+        let content = toml::to_string(&manifest).unwrap();
+        assert!(content.contains("part1 = \"0123\"\npart2 = -5\n"));
+        assert_eq!(Manifest::parse(&content), Ok(manifest));
+    }
+
+    #[test]
+    fn rejects_unknown_fields() {
+        assert!(Manifest::parse("[[example]]\ninput = \"a\"\npart3 = 1\n").is_err());
+    }
+}
@@ -0,0 +1,190 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// An error in a puzzle input, pointing at the offending text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line of the offending text, counting from 1.
+    pub line: usize,
+    /// The column of the offending text in characters, counting from 1.
+    pub column: usize,
+    pub text: String,
+    /// What was expected instead, such as "a number" or "one of L, R".
+    pub expected: String,
+}
+
+/// Returns the byte offset of `text` in `source`. Slices of `source` are found by their address,
+/// so that repeated text is located correctly, and other text by searching for it.
+fn offset(source: &str, text: &str) -> usize {
+    let start = source.as_ptr() as usize;
+    let address = text.as_ptr() as usize;
+    if address >= start && address + text.len() <= start + source.len() {
+        address - start
+    } else {
+        source.find(text).unwrap_or(0)
+    }
+}
+
+/// Returns the line and column, counting from 1, of a byte offset in `source`.
+fn position(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+impl ParseError {
+    /// Creates an error for `text`, which should be a slice of `source`, at its position there.
+    pub fn at(source: &str, text: &str, expected: impl Into<String>) -> Self {
+        let (line, column) = position(source, offset(source, text));
+        Self {
+            line,
+            column,
+            text: text.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Moves an error in `part`, a slice of `source`, to its position in `source`.
+    pub fn within(mut self, source: &str, part: &str) -> Self {
+        let (line, column) = position(source, offset(source, part));
+        if self.line == 1 {
+            self.column += column - 1;
+        }
+        self.line += line - 1;
+        self
+    }
+
+    /// Renders the error with the offending line of `input`, and carets under the offending text
+    /// up to the end of that line.
+    pub fn render(&self, input: &str) -> String {
+        let mut rendered = format!("Invalid input at {}", self);
+        let Some(line) = input.lines().nth(self.line - 1) else {
+            return rendered;
+        };
+        let gutter = " ".repeat(self.line.to_string().len());
+        let indent: String = line
+            .chars()
+            .take(self.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let text = self.text.lines().next().unwrap_or_default();
+        let carets = "^".repeat(text.chars().count().max(1));
+        rendered.push_str(&format!(
+            "\n{} |\n{} | {}\n{} | {}{}",
+            gutter, self.line, line, gutter, indent, carets
+        ));
+        rendered
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found ",
+            self.line, self.column, self.expected
+        )?;
+        if self.text.is_empty() {
+            write!(f, "nothing")
+        } else {
+            write!(f, "{:?}", self.text)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses each line of `input` that isn't blank, placing errors at their position in `input`.
+pub fn lines<T>(
+    input: &str,
+    mut parse: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| parse(line).map_err(|e| e.within(input, line)))
+        .collect()
+}
+
+/// Parses `text`, a slice of `source`, as a number.
+pub fn number<T: FromStr>(source: &str, text: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::at(source, text, "a number"))
+}
+
+/// Splits `text`, a slice of `source`, at the first `separator`, which it should contain.
+pub fn split_once<'a>(
+    source: &str,
+    text: &'a str,
+    separator: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(separator)
+        .ok_or_else(|| ParseError::at(source, &text[text.len()..], format!("{:?}", separator)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "1 2 3\n4 x 6\n4 x 6\n";
+
+    #[test]
+    fn locates_slices() {
+        let third = INPUT.lines().nth(2).unwrap();
+        let error = ParseError::at(INPUT, &third[2..3], "a number");
+        assert_eq!((error.line, error.column), (3, 3));
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 3: expected a number, found \"x\""
+        );
+    }
+
+    #[test]
+    fn parses_lines_with_positions() {
+        let parse_line = |line: &str| -> Result<Vec<u32>, ParseError> {
+            line.split(' ').map(|n| number(line, n)).collect()
+        };
+        assert_eq!(
+            lines("1 2\n\n3 4\n", parse_line),
+            Ok(vec![vec![1, 2], vec![3, 4]])
+        );
+        let error = lines(INPUT, parse_line).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+
+    #[test]
+    fn moves_errors_into_sections() {
+        let input = "a\nb\n\nc d\ne f";
+        let section = input.split("\n\n").nth(1).unwrap();
+        let line = section.lines().nth(1).unwrap();
+        let error = ParseError::at(line, &line[2..], "g")
+            .within(section, line)
+            .within(input, section);
+        assert_eq!((error.line, error.column), (5, 3));
+        let error = ParseError::at(section, &section[2..3], "g").within(input, section);
+        assert_eq!((error.line, error.column), (4, 3));
+    }
+
+    #[test]
+    fn renders_caret_under_text() {
+        let error = number::<u32>(INPUT, &INPUT[8..9]).unwrap_err();
+        assert_eq!(
+            error.render(INPUT),
+            "Invalid input at line 2, column 3: expected a number, found \"x\"\n  |\n2 | 4 x 6\n  |   ^"
+        );
+    }
+
+    #[test]
+    fn points_at_missing_separator() {
+        let error = split_once(INPUT, &INPUT[..5], ": ").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 6));
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 6: expected \": \", found nothing"
+        );
+    }
+}
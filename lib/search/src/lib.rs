@@ -0,0 +1,288 @@
+//! Searches for the cheapest path through a space of states, given the states to start from,
+//! the successors of each state with the cost of moving to them, and which states are goals.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A path from a start to a goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    /// The states along the path, including the start and the goal.
+    pub states: Vec<S>,
+    pub cost: C,
+}
+
+/// The outcome of a search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Search<S, C> {
+    /// The cheapest path to a goal, if any goal can be reached.
+    pub path: Option<Path<S, C>>,
+    /// The number of states whose successors were explored, or that turned out to be a goal.
+    pub visited: usize,
+}
+
+/// The states reached so far, each with the state it was reached from and the lowest cost to
+/// reach it.
+struct Reached<S, C> {
+    indices: HashMap<S, usize>,
+    states: Vec<(S, Option<usize>, C)>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy + Ord> Reached<S, C> {
+    fn new() -> Self {
+        Self {
+            indices: HashMap::new(),
+            states: vec![],
+        }
+    }
+
+    /// Records reaching a state at a cost, returning its index unless it was reached as cheaply
+    /// before.
+    fn reach(&mut self, state: S, from: Option<usize>, cost: C) -> Option<usize> {
+        match self.indices.get(&state) {
+            Some(&i) if self.states[i].2 <= cost => None,
+            Some(&i) => {
+                self.states[i].1 = from;
+                self.states[i].2 = cost;
+                Some(i)
+            }
+            None => {
+                let i = self.states.len();
+                self.indices.insert(state.clone(), i);
+                self.states.push((state, from, cost));
+                Some(i)
+            }
+        }
+    }
+
+    fn cost(&self, i: usize) -> C {
+        self.states[i].2
+    }
+
+    /// Returns the path to the state at index `i`, following the states it was reached from.
+    fn path(&self, mut i: usize) -> Path<S, C> {
+        let cost = self.cost(i);
+        let mut states = vec![];
+        loop {
+            let (state, from, _) = &self.states[i];
+            states.push(state.clone());
+            match from {
+                Some(from) => i = *from,
+                None => break,
+            }
+        }
+        states.reverse();
+        Path { states, cost }
+    }
+}
+
+/// Finds the cheapest path with Dijkstra's algorithm. Costs should not be negative.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// Finds the cheapest path with A*, exploring the states whose cost plus `heuristic` is lowest
+/// first. The heuristic should never overestimate the cost to reach a goal, or the path found
+/// may not be the cheapest.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut reached = Reached::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        let estimate = heuristic(&start);
+        if let Some(i) = reached.reach(start, None, C::default()) {
+            queue.push(Reverse((estimate, C::default(), i)));
+        }
+    }
+
+    let mut visited = 0;
+    while let Some(Reverse((_, cost, i))) = queue.pop() {
+        // Skip states that were reached more cheaply after they were queued
+        if cost > reached.cost(i) {
+            continue;
+        }
+        visited += 1;
+        let state = reached.states[i].0.clone();
+        if is_goal(&state) {
+            return Search {
+                path: Some(reached.path(i)),
+                visited,
+            };
+        }
+        for (next, step) in successors(&state) {
+            let estimate = heuristic(&next);
+            if let Some(j) = reached.reach(next, Some(i), cost + step) {
+                queue.push(Reverse((cost + step + estimate, cost + step, j)));
+            }
+        }
+    }
+    Search {
+        path: None,
+        visited,
+    }
+}
+
+/// Finds the cheapest path when every step costs either 0 or 1, with a double-ended queue
+/// instead of a priority queue.
+pub fn zero_one_bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, u64>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut reached = Reached::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Some(i) = reached.reach(start, None, 0) {
+            queue.push_back((0, i));
+        }
+    }
+
+    let mut visited = 0;
+    while let Some((cost, i)) = queue.pop_front() {
+        if cost > reached.cost(i) {
+            continue;
+        }
+        visited += 1;
+        let state = reached.states[i].0.clone();
+        if is_goal(&state) {
+            return Search {
+                path: Some(reached.path(i)),
+                visited,
+            };
+        }
+        for (next, step) in successors(&state) {
+            assert!(
+                step <= 1,
+                "Steps of a 0-1 BFS should cost 0 or 1, not {}",
+                step
+            );
+            if let Some(j) = reached.reach(next, Some(i), cost + step) {
+                if step == 0 {
+                    queue.push_front((cost, j));
+                } else {
+                    queue.push_back((cost + 1, j));
+                }
+            }
+        }
+    }
+    Search {
+        path: None,
+        visited,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A maze of walls `#` and open cells, where `~` is water that costs 5 to cross.
+    const MAZE: [&str; 5] = [
+        "S.#....", //
+        ".##.##.", //
+        "..~..#.", //
+        "#.##.#.", //
+        "......E",
+    ];
+
+    type Cell = (usize, usize);
+
+    fn find(c: char) -> Cell {
+        MAZE.iter()
+            .enumerate()
+            .find_map(|(y, row)| Some((row.find(c)?, y)))
+            .unwrap()
+    }
+
+    fn moves((x, y): &Cell) -> Vec<(Cell, u64)> {
+        let neighbors = [
+            (x + 1, *y),
+            (*x, y + 1),
+            (x.wrapping_sub(1), *y),
+            (*x, y.wrapping_sub(1)),
+        ];
+        neighbors
+            .into_iter()
+            .filter_map(|(x, y)| match MAZE.get(y)?.as_bytes().get(x)? {
+                b'#' => None,
+                b'~' => Some(((x, y), 5)),
+                _ => Some(((x, y), 1)),
+            })
+            .collect()
+    }
+
+    fn manhattan(&(x, y): &Cell) -> u64 {
+        let (goal_x, goal_y) = find('E');
+        (x.abs_diff(goal_x) + y.abs_diff(goal_y)) as u64
+    }
+
+    #[test]
+    fn finds_cheapest_path() {
+        let goal = find('E');
+        let search = dijkstra([find('S')], moves, |cell| *cell == goal);
+        let path = search.path.unwrap();
+        assert_eq!(path.cost, 10);
+        assert_eq!(path.states.len(), 11);
+        assert_eq!(path.states.first(), Some(&(0, 0)));
+        assert_eq!(path.states.last(), Some(&goal));
+        assert!(!path.states.contains(&find('~')));
+        assert!(path
+            .states
+            .windows(2)
+            .all(|pair| moves(&pair[0]).iter().any(|(next, _)| *next == pair[1])));
+    }
+
+    #[test]
+    fn estimates_with_heuristic() {
+        let goal = find('E');
+        let plain = dijkstra([find('S')], moves, |cell| *cell == goal);
+        let guided = astar([find('S')], moves, manhattan, |cell| *cell == goal);
+        assert_eq!(guided.path.as_ref().unwrap().cost, 10);
+        assert!(guided.visited <= plain.visited);
+    }
+
+    #[test]
+    fn counts_zero_cost_steps_first() {
+        // Moving right is free and moving down costs 1, within a grid of 4 by 3
+        let successors = |&(x, y): &Cell| {
+            [((x + 1, y), 0), ((x, y + 1), 1)]
+                .into_iter()
+                .filter(|((x, y), _)| *x < 4 && *y < 3)
+        };
+        let search = zero_one_bfs([(0, 0)], successors, |cell| *cell == (3, 2));
+        let path = search.path.unwrap();
+        assert_eq!(path.cost, 2);
+        assert_eq!(path.states.len(), 6);
+    }
+
+    #[test]
+    fn reports_unreachable_goals() {
+        let search = dijkstra([find('S')], moves, |cell| *cell == (2, 0));
+        assert_eq!(search.path, None);
+        // Every open cell was visited
+        assert_eq!(search.visited, 25);
+    }
+}